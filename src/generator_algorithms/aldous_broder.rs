@@ -0,0 +1,60 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::Coord;
+use crate::generator_algorithms::generator_helpers::{choose_rand_direction, random_grid_position, remove_walls_between_cells, GeneratorAlgo, GeneratorStatus};
+
+pub struct AldousBroder {
+    current_cell: Coord,
+    unvisited_count: usize,
+    status: GeneratorStatus,
+    rng: StdRng,
+}
+
+impl GeneratorAlgo for AldousBroder {
+    fn create_maze(&mut self, maze: &mut Maze) {
+        match self.status {
+            GeneratorStatus::Initialized => {
+                self.current_cell = random_grid_position(maze, &mut self.rng);
+                maze.visit_cell(&self.current_cell);
+                self.unvisited_count = maze.cell_count() - 1;
+                self.status = GeneratorStatus::InProgress;
+            }
+            GeneratorStatus::InProgress => {
+                let direction = choose_rand_direction(maze, &self.current_cell, &mut self.rng);
+                let next_cell = maze.neighbor(&self.current_cell, direction)
+                    .expect("Random direction has no neighbor");
+
+                if !maze.get_cell_ref(&next_cell).visited() {
+                    remove_walls_between_cells(maze, &self.current_cell, direction);
+                    maze.visit_cell(&next_cell);
+                    self.unvisited_count -= 1;
+                }
+                self.current_cell = next_cell;
+
+                if self.unvisited_count == 0 {
+                    self.status = GeneratorStatus::Done;
+                }
+            }
+            GeneratorStatus::Done => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &GeneratorStatus {
+        &self.status
+    }
+}
+
+impl AldousBroder {
+    pub fn new(seed: u64) -> Self {
+        AldousBroder {
+            current_cell: Coord{ y: 0, x: 0 },
+            unvisited_count: 0,
+            status: GeneratorStatus::Initialized,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,98 @@
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::Coord;
+use crate::structures::algo_params::{param_choice, AlgoParam, AlgoParamKind, AlgoParams};
+use crate::generator_algorithms::generator_helpers::{remove_walls_between_cells, GeneratorAlgo, GeneratorStatus};
+
+pub struct BinaryTree {
+    current_row: usize,
+    current_col: usize,
+    vertical_direction: usize,
+    horizontal_direction: usize,
+    status: GeneratorStatus,
+    rng: StdRng,
+}
+
+impl GeneratorAlgo for BinaryTree {
+    fn create_maze(&mut self, maze: &mut Maze) {
+        match self.status {
+            GeneratorStatus::Initialized => {
+                self.process_cell(maze);
+                self.status = GeneratorStatus::InProgress;
+            }
+            GeneratorStatus::InProgress => {
+                self.process_cell(maze);
+                if self.current_row == maze.height() {
+                    self.status = GeneratorStatus::Done;
+                }
+            }
+            GeneratorStatus::Done => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &GeneratorStatus {
+        &self.status
+    }
+}
+
+impl BinaryTree {
+    pub fn new(seed: u64, params: &AlgoParams) -> Self {
+        let (vertical_direction, horizontal_direction) = match param_choice(params, "bias", "north_east") {
+            "north_west" => (0, 3),
+            "south_east" => (2, 1),
+            "south_west" => (2, 3),
+            _ => (0, 1),
+        };
+
+        BinaryTree {
+            current_row: 0,
+            current_col: 0,
+            vertical_direction,
+            horizontal_direction,
+            status: GeneratorStatus::Initialized,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    fn process_cell(&mut self, maze: &mut Maze) {
+        let current_cell = Coord{ y: self.current_row, x: self.current_col };
+        maze.visit_cell(&current_cell);
+
+        // Wrapped edges are skipped, or every cell would have a passage to carve and the tree would close into loops.
+        let directions: Vec<usize> = [self.vertical_direction, self.horizontal_direction].into_iter()
+            .filter(|direction| maze.neighbor(&current_cell, *direction).is_some() && !maze.crosses_edge(&current_cell, *direction))
+            .collect();
+        if let Some(direction) = directions.choose(&mut self.rng) {
+            remove_walls_between_cells(maze, &current_cell, *direction);
+        }
+
+        self.current_col += 1;
+        if self.current_col == maze.width() {
+            self.current_col = 0;
+            self.current_row += 1;
+        }
+    }
+}
+
+pub fn get_binary_tree_params() -> Vec<AlgoParam> {
+    vec![
+        AlgoParam {
+            id: "bias".to_string(),
+            label: "Bias".to_string(),
+            kind: AlgoParamKind::Choice {
+                options: vec![
+                    ("north_east".to_string(), "North-East".to_string()),
+                    ("north_west".to_string(), "North-West".to_string()),
+                    ("south_east".to_string(), "South-East".to_string()),
+                    ("south_west".to_string(), "South-West".to_string()),
+                ],
+                default: "north_east".to_string(),
+            },
+        },
+    ]
+}
\ No newline at end of file
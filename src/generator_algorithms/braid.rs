@@ -0,0 +1,145 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::Coord;
+use crate::generator_algorithms::generator_helpers::{remove_walls_between_cells, GeneratorAlgo, GeneratorStatus};
+
+pub struct Braid {
+    generator: Box<dyn GeneratorAlgo>,
+    braid_fraction: f64,
+    dead_ends: Vec<Coord>,
+    status: GeneratorStatus,
+    rng: StdRng,
+}
+
+impl GeneratorAlgo for Braid {
+    fn create_maze(&mut self, maze: &mut Maze) {
+        match self.status {
+            GeneratorStatus::Initialized => {
+                self.generator.create_maze(maze);
+                self.status = GeneratorStatus::InProgress;
+                if self.generator.status() == &GeneratorStatus::Done {
+                    self.collect_dead_ends(maze);
+                }
+            }
+            GeneratorStatus::InProgress => {
+                if self.generator.status() != &GeneratorStatus::Done {
+                    self.generator.create_maze(maze);
+                    if self.generator.status() == &GeneratorStatus::Done {
+                        self.collect_dead_ends(maze);
+                    }
+                }
+                else if let Some(dead_end) = self.dead_ends.pop() {
+                    self.remove_dead_end(maze, &dead_end);
+                }
+
+                if self.generator.status() == &GeneratorStatus::Done && self.dead_ends.is_empty() {
+                    self.status = GeneratorStatus::Done;
+                }
+            }
+            GeneratorStatus::Done => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &GeneratorStatus {
+        &self.status
+    }
+}
+
+impl Braid {
+    pub fn new(generator: Box<dyn GeneratorAlgo>, seed: u64, braid_percent: usize) -> Self {
+        Braid {
+            generator,
+            braid_fraction: braid_percent.min(100) as f64 / 100.0,
+            dead_ends: Vec::new(),
+            status: GeneratorStatus::Initialized,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    fn collect_dead_ends(&mut self, maze: &Maze) {
+        let mut dead_ends: Vec<Coord> = maze.grid().iter()
+            .filter(|cell| is_dead_end(maze, cell.coord()))
+            .map(|cell| *cell.coord())
+            .collect();
+        dead_ends.shuffle(&mut self.rng);
+
+        let braid_count = (dead_ends.len() as f64 * self.braid_fraction).round() as usize;
+        dead_ends.truncate(braid_count);
+        self.dead_ends = dead_ends;
+    }
+
+    fn remove_dead_end(&mut self, maze: &mut Maze, dead_end: &Coord) {
+        if !is_dead_end(maze, dead_end) {
+            return;
+        }
+
+        let walled_directions: Vec<usize> = (0..maze.direction_count(dead_end))
+            .filter(|direction| maze.get_cell_ref(dead_end).walls()[*direction])
+            .filter(|direction| maze.neighbor(dead_end, *direction).is_some())
+            .collect();
+        let dead_end_neighbors: Vec<usize> = walled_directions.iter()
+            .copied()
+            .filter(|direction| {
+                let neighbor = maze.neighbor(dead_end, *direction).expect("Neighbor is outside the maze");
+                is_dead_end(maze, &neighbor)
+            })
+            .collect();
+
+        let candidates = if dead_end_neighbors.is_empty() { &walled_directions } else { &dead_end_neighbors };
+        if let Some(direction) = candidates.choose(&mut self.rng) {
+            remove_walls_between_cells(maze, dead_end, *direction);
+        }
+    }
+}
+
+pub fn is_dead_end(maze: &Maze, coord: &Coord) -> bool {
+    let walls = maze.get_cell_ref(coord).walls();
+    (0..maze.direction_count(coord)).filter(|direction| !walls[*direction]).count() == 1
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::generator_algorithms::generator_helpers::get_generator_algo;
+    use crate::structures::algo_params::AlgoParams;
+    use crate::structures::topology::MAX_DIRECTIONS;
+
+    pub(crate) fn braided(seed: u64, braid_percent: usize) -> Maze {
+        let mut maze = Maze::new(9, 12);
+        let generator = get_generator_algo("recursive_backtracker", seed, &AlgoParams::new());
+        let mut braid = Braid::new(generator, seed, braid_percent);
+        while braid.status() != &GeneratorStatus::Done {
+            braid.create_maze(&mut maze);
+        }
+        maze
+    }
+
+    fn walls(maze: &Maze) -> Vec<[bool; MAX_DIRECTIONS]> {
+        maze.grid().iter().map(|cell| *cell.walls()).collect()
+    }
+
+    #[test]
+    fn full_braid_leaves_no_dead_ends() {
+        for seed in 0..5 {
+            let maze = braided(seed, 100);
+            assert!(maze.grid().iter().all(|cell| maze.passages(cell.coord()).len() != 1), "a dead end was left");
+        }
+    }
+
+    #[test]
+    fn zero_braid_leaves_the_maze_unchanged() {
+        for seed in 0..5 {
+            let mut maze = Maze::new(9, 12);
+            let mut generator = get_generator_algo("recursive_backtracker", seed, &AlgoParams::new());
+            while generator.status() != &GeneratorStatus::Done {
+                generator.create_maze(&mut maze);
+            }
+            assert!(walls(&braided(seed, 0)) == walls(&maze));
+        }
+    }
+}
\ No newline at end of file
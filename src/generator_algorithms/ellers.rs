@@ -1,21 +1,24 @@
-use std::collections::{HashMap, HashSet};
-use rand::{Rng};
+use std::collections::{BTreeSet, HashMap};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use dioxus::prelude::*;
 use crate::structures::maze::Maze;
 use crate::structures::cell::Coord;
+use crate::structures::algo_params::{param_number, AlgoParam, AlgoParamKind, AlgoParams};
 use crate::generator_algorithms::generator_helpers::{remove_walls_between_cells, GeneratorAlgo, GeneratorStatus};
 
 pub struct Ellers {
     current_row: usize,
     current_col: usize,
     set_identifier: usize,
-    sets_needing_vertical_connection: HashSet<usize>,
+    sets_needing_vertical_connection: BTreeSet<usize>,
     sets: HashMap<usize, Vec<Coord>>,
     cells: HashMap<Coord, usize>,
     stage: Stage,
+    horizontal_merge_probability: f64,
+    vertical_connection_probability: f64,
     status: GeneratorStatus,
-    rng: rand::rngs::ThreadRng,
+    rng: StdRng,
 }
 
 enum AddCell {
@@ -30,9 +33,7 @@ enum Stage {
 }
 
 impl GeneratorAlgo for Ellers {
-    fn create_maze(&mut self, maze: &mut Signal<Maze>) {
-        let maze: &mut Maze = &mut maze.write();
-
+    fn create_maze(&mut self, maze: &mut Maze) {
         match self.status {
             GeneratorStatus::Initialized => {
                 self.process_maze_row(maze);
@@ -56,17 +57,19 @@ impl GeneratorAlgo for Ellers {
 }
 
 impl Ellers {
-    pub fn new() -> Self {
+    pub fn new(seed: u64, params: &AlgoParams) -> Self {
         Ellers {
             current_row: 0,
             current_col: 0,
             set_identifier: 0,
-            sets_needing_vertical_connection: HashSet::new(),
+            sets_needing_vertical_connection: BTreeSet::new(),
             sets: HashMap::new(),
             cells: HashMap::new(),
             stage: Stage::Horizontal,
+            horizontal_merge_probability: param_number(params, "horizontal_merge", 50) as f64 / 100.0,
+            vertical_connection_probability: param_number(params, "vertical_density", 50) as f64 / 100.0,
             status: GeneratorStatus::Initialized,
-            rng: rand::thread_rng(),
+            rng: StdRng::seed_from_u64(seed),
         }
     }
 
@@ -87,7 +90,7 @@ impl Ellers {
             }
             if self.current_col > 0 {
                 let previous_cell = &Coord{ x: self.current_col - 1, y: self.current_row };
-                if self.cells.get(current_cell) != self.cells.get(previous_cell) && self.rng.gen_bool(0.5) {
+                if self.cells.get(current_cell) != self.cells.get(previous_cell) && self.rng.gen_bool(self.horizontal_merge_probability) {
                     self.merge_cell_sets(current_cell, previous_cell);
                     remove_walls_between_cells(maze, current_cell, 3);
                 }
@@ -108,7 +111,7 @@ impl Ellers {
 
     fn vertical_process(&mut self, maze: &mut Maze) {
         if self.current_col < maze.width() {
-            if self.rng.gen_bool(0.5) {
+            if self.rng.gen_bool(self.vertical_connection_probability) {
                 let current_cell = &Coord { x: self.current_col, y: self.current_row };
                 let next_cell = &Coord { x: self.current_col, y: self.current_row + 1 };
 
@@ -198,4 +201,19 @@ impl Ellers {
         self.sets.entry(first_set_id).and_modify(|existing_cells| existing_cells.extend(second_set_cells));
         self.sets.remove(&second_set_id);
     }
+}
+
+pub fn get_ellers_params() -> Vec<AlgoParam> {
+    vec![
+        AlgoParam {
+            id: "horizontal_merge".to_string(),
+            label: "Horizontal merge %".to_string(),
+            kind: AlgoParamKind::Number { min: 0, max: 100, default: 50 },
+        },
+        AlgoParam {
+            id: "vertical_density".to_string(),
+            label: "Vertical density %".to_string(),
+            kind: AlgoParamKind::Number { min: 0, max: 100, default: 50 },
+        },
+    ]
 }
\ No newline at end of file
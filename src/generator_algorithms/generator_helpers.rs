@@ -1,12 +1,22 @@
-use dioxus::prelude::Signal;
-use rand::{thread_rng, Rng};
+use rand::Rng;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
 
 use crate::structures::maze::Maze;
 use crate::structures::cell::Coord;
-use crate::generator_algorithms::ellers::Ellers;
+use crate::structures::topology::Topology;
+use crate::structures::algo_params::{AlgoParam, AlgoParamKind, AlgoParams};
+use crate::generator_algorithms::aldous_broder::AldousBroder;
+use crate::generator_algorithms::binary_tree::{get_binary_tree_params, BinaryTree};
+use crate::generator_algorithms::ellers::{get_ellers_params, Ellers};
+use crate::generator_algorithms::growing_tree::{get_growing_tree_params, GrowingTree};
+use crate::generator_algorithms::hunt_and_kill::HuntAndKill;
+use crate::generator_algorithms::kruskals::Kruskals;
 use crate::generator_algorithms::random_prim::RandomPrim;
 use crate::generator_algorithms::recursive_backtracker::RecursiveBacktracker;
+use crate::generator_algorithms::recursive_division::RecursiveDivision;
+use crate::generator_algorithms::sidewinder::{get_sidewinder_params, Sidewinder};
+use crate::generator_algorithms::wilsons::Wilsons;
 
 #[derive(PartialEq)]
 pub enum GeneratorStatus {
@@ -16,32 +26,87 @@ pub enum GeneratorStatus {
 }
 
 pub trait GeneratorAlgo {
-    fn create_maze(&mut self, maze: &mut Signal<Maze>);
+    fn create_maze(&mut self, maze: &mut Maze);
 
     fn status(&self) -> &GeneratorStatus;
 }
 
-pub fn get_generator_options() -> Vec<(String, String)> {
-    vec![
+pub fn get_generator_options(topology: Topology) -> Vec<(String, String)> {
+    let options = vec![
+        ("aldous_broder".to_string(), "Aldous-Broder".to_string()),
+        ("binary_tree".to_string(), "Binary Tree".to_string()),
         ("ellers".to_string(), "Eller's".to_string()),
+        ("growing_tree".to_string(), "Growing Tree".to_string()),
+        ("hunt_and_kill".to_string(), "Hunt and Kill".to_string()),
+        ("kruskals".to_string(), "Kruskal's".to_string()),
         ("random_prim".to_string(),"Random Prim".to_string()),
-        ("recursive_backtracker".to_string(),"Recursive Backtracker".to_string())
-    ]
+        ("recursive_backtracker".to_string(),"Recursive Backtracker".to_string()),
+        ("recursive_division".to_string(), "Recursive Division".to_string()),
+        ("sidewinder".to_string(), "Sidewinder".to_string()),
+        ("wilsons".to_string(), "Wilson's".to_string()),
+    ];
+
+    options.into_iter()
+        .filter(|(algo, _)| generator_supports_topology(algo, topology))
+        .collect()
+}
+
+pub fn generator_supports_topology(algo: &str, topology: Topology) -> bool {
+    match algo {
+        "binary_tree" | "ellers" | "recursive_division" | "sidewinder" => topology == Topology::Square,
+        _ => true,
+    }
 }
 
+// These carve toward fixed sides, row by row or split the grid into rectangles, so they have no way to use the wrapped edges
+pub fn generator_supports_wrap(algo: &str) -> bool {
+    !matches!(algo, "binary_tree" | "ellers" | "recursive_division" | "sidewinder")
+}
+
+pub fn get_generator_params(algo: &str) -> Vec<AlgoParam> {
+    match algo {
+        "binary_tree" => get_binary_tree_params(),
+        "ellers" => get_ellers_params(),
+        "growing_tree" => get_growing_tree_params(),
+        "kruskals" | "recursive_backtracker" => vec![weave_param()],
+        "sidewinder" => get_sidewinder_params(),
+        _ => Vec::new(),
+    }
+}
 
-pub fn get_generator_algo(algo: &str) -> Box<dyn GeneratorAlgo> {
+pub fn get_generator_algo(algo: &str, seed: u64, params: &AlgoParams) -> Box<dyn GeneratorAlgo> {
     match algo {
-        "ellers" => Box::new(Ellers::new()),
-        "random_prim" => Box::new(RandomPrim::new()),
-        "recursive_backtracker" => Box::new(RecursiveBacktracker::new()),
+        "aldous_broder" => Box::new(AldousBroder::new(seed)),
+        "binary_tree" => Box::new(BinaryTree::new(seed, params)),
+        "ellers" => Box::new(Ellers::new(seed, params)),
+        "growing_tree" => Box::new(GrowingTree::new(seed, params)),
+        "hunt_and_kill" => Box::new(HuntAndKill::new(seed)),
+        "kruskals" => Box::new(Kruskals::new(seed, params)),
+        "random_prim" => Box::new(RandomPrim::new(seed)),
+        "recursive_backtracker" => Box::new(RecursiveBacktracker::new(seed, params)),
+        "recursive_division" => Box::new(RecursiveDivision::new(seed)),
+        "sidewinder" => Box::new(Sidewinder::new(seed, params)),
+        "wilsons" => Box::new(Wilsons::new(seed)),
         _ => panic!("you shouldn't be here"),
     }
 }
 
-pub fn random_grid_position (maze: &Maze) -> Coord {
-    let y = thread_rng().gen_range(0..maze.height());
-    let x = thread_rng().gen_range(0..maze.width());
+// Weaving only applies to square grids, other topologies ignore it.
+pub fn weave_param() -> AlgoParam {
+    AlgoParam {
+        id: "weave".to_string(),
+        label: "Weave %".to_string(),
+        kind: AlgoParamKind::Number { min: 0, max: 100, default: 0 },
+    }
+}
+
+pub fn random_seed() -> u64 {
+    rand::thread_rng().gen::<u32>() as u64
+}
+
+pub fn random_grid_position (maze: &Maze, rng: &mut StdRng) -> Coord {
+    let y = rng.gen_range(0..maze.height());
+    let x = rng.gen_range(0..maze.row_width(y));
     Coord {
         y,
         x,
@@ -49,44 +114,89 @@ pub fn random_grid_position (maze: &Maze) -> Coord {
 }
 
 pub fn remove_walls_between_cells(maze: &mut Maze, frontier_cell: &Coord, direction: usize) {
-    match direction {
-        0 => {
-            maze.remove_cell_wall(frontier_cell, "top");
-            maze.remove_cell_wall(&Coord{ y: frontier_cell.y - 1, x: frontier_cell.x }, "bottom");
-        }
-        1 => {
-            maze.remove_cell_wall(frontier_cell, "right");
-            maze.remove_cell_wall(&Coord{ y: frontier_cell.y, x: frontier_cell.x + 1 }, "left");
-        }
-        2 => {
-            maze.remove_cell_wall(frontier_cell, "bottom");
-            maze.remove_cell_wall(&Coord{ y: frontier_cell.y + 1, x: frontier_cell.x }, "top");
-        }
-        3 => {
-            maze.remove_cell_wall(frontier_cell, "left");
-            maze.remove_cell_wall(&Coord{ y: frontier_cell.y, x: frontier_cell.x - 1 }, "right");
-        }
-        _ => {}
+    if let Some(neighbor) = maze.neighbor(frontier_cell, direction) {
+        maze.remove_wall_between(frontier_cell, &neighbor);
     }
 }
 
-pub fn choose_rand_neighbor(maze: &Maze, frontier_cell: &Coord, visited_status: bool) -> Result<usize, &'static str> {
-    let mut directions = [0, 1, 2, 3];
-    directions.shuffle(&mut thread_rng());
+pub fn add_walls_between_cells(maze: &mut Maze, cell: &Coord, direction: usize) {
+    if let Some(neighbor) = maze.neighbor(cell, direction) {
+        maze.add_wall_between(cell, &neighbor);
+    }
+}
+
+pub fn choose_rand_direction(maze: &Maze, cell: &Coord, rng: &mut StdRng) -> usize {
+    let directions: Vec<usize> = maze.neighbors(cell).into_iter()
+        .map(|(direction, _)| direction)
+        .collect();
+    *directions.choose(rng).expect("Cell has no neighbors")
+}
 
+pub fn choose_rand_neighbor(maze: &Maze, frontier_cell: &Coord, visited_status: bool, rng: &mut StdRng) -> Result<usize, &'static str> {
+    let mut directions: Vec<usize> = (0..maze.direction_count(frontier_cell)).collect();
+    directions.shuffle(rng);
     for direction in directions {
-        if direction == 0 && frontier_cell.y > 0 && maze.get_cell_ref(&Coord{ y: frontier_cell.y - 1, x: frontier_cell.x }).visited() == visited_status {
-            return Ok(direction);
+        if let Some(neighbor) = maze.neighbor(frontier_cell, direction) {
+            if maze.get_cell_ref(&neighbor).visited() == visited_status {
+                return Ok(direction);
+            }
         }
-        if direction == 1 && frontier_cell.x < maze.width() - 1 && maze.get_cell_ref(&Coord{ y: frontier_cell.y, x: frontier_cell.x + 1 }).visited() == visited_status {
-            return Ok(direction);
+    }
+    Err("no neighboring cells in that state")
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::structures::algo_params::default_params;
+
+    pub(crate) fn generate(maze: &mut Maze, algo: &str, seed: u64) {
+        let mut generator = get_generator_algo(algo, seed, &default_params(get_generator_params(algo)));
+        while generator.status() != &GeneratorStatus::Done {
+            generator.create_maze(maze);
         }
-        if direction == 2 && frontier_cell.y < maze.height() - 1 && maze.get_cell_ref(&Coord{ y: frontier_cell.y + 1, x: frontier_cell.x }).visited() == visited_status {
-            return Ok(direction);
+    }
+
+    pub(crate) fn reachable_cells(maze: &Maze, from: &Coord) -> HashSet<Coord> {
+        let mut reached: HashSet<Coord> = HashSet::from([*from]);
+        let mut stack: Vec<Coord> = vec![*from];
+        while let Some(cell) = stack.pop() {
+            for (_, next) in maze.passages(&cell) {
+                if reached.insert(next) {
+                    stack.push(next);
+                }
+            }
         }
-        if direction == 3 && frontier_cell.x > 0 && maze.get_cell_ref(&Coord{ y: frontier_cell.y, x: frontier_cell.x - 1 }).visited() == visited_status {
-            return Ok(direction);
+        reached
+    }
+
+    pub(crate) fn passage_count(maze: &Maze) -> usize {
+        maze.grid().iter().map(|cell| maze.passages(cell.coord()).len()).sum::<usize>() / 2
+    }
+
+    #[test]
+    fn generators_carve_spanning_trees() {
+        for (algo, _) in get_generator_options(Topology::Square) {
+            for seed in 0..5 {
+                let mut maze = Maze::new(9, 12);
+                generate(&mut maze, &algo, seed);
+                assert_eq!(reachable_cells(&maze, &Coord{ y: 0, x: 0 }).len(), maze.cell_count(), "{algo} left cells unreachable");
+                assert_eq!(passage_count(&maze), maze.cell_count() - 1, "{algo} carved a loop");
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_carves_same_walls() {
+        let carve = |algo: &str, seed: u64| {
+            let mut maze = Maze::new(8, 11);
+            generate(&mut maze, algo, seed);
+            maze.grid().iter().map(|cell| *cell.walls()).collect::<Vec<_>>()
+        };
+        for (algo, _) in get_generator_options(Topology::Square) {
+            assert!(carve(&algo, 42) == carve(&algo, 42), "{algo} is not reproducible from its seed");
         }
     }
-    Err("no neighboring cells in that state")
 }
\ No newline at end of file
@@ -0,0 +1,141 @@
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::Coord;
+use crate::structures::algo_params::{param_choice, param_number, AlgoParam, AlgoParamKind, AlgoParams};
+use crate::generator_algorithms::generator_helpers::{choose_rand_neighbor, random_grid_position, remove_walls_between_cells, GeneratorAlgo, GeneratorStatus};
+
+pub struct GrowingTree {
+    active: Vec<Coord>,
+    selection: CellSelection,
+    random_probability: f64,
+    status: GeneratorStatus,
+    rng: StdRng,
+}
+
+#[derive(Clone, Copy)]
+enum CellSelection {
+    Newest,
+    Oldest,
+    Middle,
+}
+
+impl GeneratorAlgo for GrowingTree {
+    fn create_maze(&mut self, maze: &mut Maze) {
+        match self.status {
+            GeneratorStatus::Initialized => {
+                let start: Coord = random_grid_position(maze, &mut self.rng);
+                maze.visit_cell(&start);
+                self.active.push(start);
+                self.status = GeneratorStatus::InProgress;
+            }
+            GeneratorStatus::InProgress => {
+                let index = self.select_index();
+                let current_cell = self.active[index];
+                match choose_rand_neighbor(maze, &current_cell, false, &mut self.rng) {
+                    Ok(direction) => {
+                        let next_cell = maze.neighbor(&current_cell, direction)
+                            .expect("Unvisited neighbor is outside the maze");
+                        remove_walls_between_cells(maze, &current_cell, direction);
+                        maze.visit_cell(&next_cell);
+                        self.active.push(next_cell);
+                    }
+                    Err(_) => {
+                        self.active.remove(index);
+                    }
+                }
+                if self.active.is_empty() {
+                    self.status = GeneratorStatus::Done;
+                }
+            }
+            GeneratorStatus::Done => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &GeneratorStatus {
+        &self.status
+    }
+}
+
+impl GrowingTree {
+    pub fn new(seed: u64, params: &AlgoParams) -> Self {
+        let selection = match param_choice(params, "cell_selection", "newest") {
+            "oldest" => CellSelection::Oldest,
+            "middle" => CellSelection::Middle,
+            _ => CellSelection::Newest,
+        };
+
+        GrowingTree {
+            active: Vec::new(),
+            selection,
+            random_probability: param_number(params, "random_weight", 0).min(100) as f64 / 100.0,
+            status: GeneratorStatus::Initialized,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    // Each pick is a random active cell with the weighted probability, otherwise the selected one
+    fn select_index(&mut self) -> usize {
+        if self.rng.gen_bool(self.random_probability) {
+            return self.rng.gen_range(0..self.active.len());
+        }
+
+        match self.selection {
+            CellSelection::Newest => self.active.len() - 1,
+            CellSelection::Oldest => 0,
+            CellSelection::Middle => self.active.len() / 2,
+        }
+    }
+}
+
+pub fn get_growing_tree_params() -> Vec<AlgoParam> {
+    vec![
+        AlgoParam {
+            id: "cell_selection".to_string(),
+            label: "Cell Selection".to_string(),
+            kind: AlgoParamKind::Choice {
+                options: vec![
+                    ("newest".to_string(), "Newest".to_string()),
+                    ("oldest".to_string(), "Oldest".to_string()),
+                    ("middle".to_string(), "Middle".to_string()),
+                ],
+                default: "newest".to_string(),
+            },
+        },
+        AlgoParam {
+            id: "random_weight".to_string(),
+            label: "Random %".to_string(),
+            kind: AlgoParamKind::Number { min: 0, max: 100, default: 0 },
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator_algorithms::generator_helpers::tests::{passage_count, reachable_cells};
+
+    #[test]
+    fn every_random_weight_carves_a_spanning_tree() {
+        for selection in ["newest", "oldest", "middle"] {
+            for weight in [0, 25, 50, 75, 100] {
+                let params = AlgoParams::from([
+                    ("cell_selection".to_string(), selection.to_string()),
+                    ("random_weight".to_string(), weight.to_string()),
+                ]);
+                for seed in 0..3 {
+                    let mut maze = Maze::new(9, 12);
+                    let mut growing_tree = GrowingTree::new(seed, &params);
+                    while growing_tree.status != GeneratorStatus::Done {
+                        growing_tree.create_maze(&mut maze);
+                    }
+                    assert_eq!(reachable_cells(&maze, &Coord{ y: 0, x: 0 }).len(), maze.cell_count(), "{selection} at {weight}% left cells unreachable");
+                    assert_eq!(passage_count(&maze), maze.cell_count() - 1, "{selection} at {weight}% carved a loop");
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,126 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::{CellState, Coord};
+use crate::generator_algorithms::generator_helpers::{choose_rand_neighbor, random_grid_position, remove_walls_between_cells, GeneratorAlgo, GeneratorStatus};
+
+pub struct HuntAndKill {
+    current_cell: Coord,
+    unvisited_count: usize,
+    first_unfinished_row: usize,
+    scan_row: usize,
+    highlighted: Vec<Coord>,
+    phase: Phase,
+    status: GeneratorStatus,
+    rng: StdRng,
+}
+
+enum Phase {
+    Kill,
+    Hunt,
+}
+
+impl GeneratorAlgo for HuntAndKill {
+    fn create_maze(&mut self, maze: &mut Maze) {
+        match self.status {
+            GeneratorStatus::Initialized => {
+                self.current_cell = random_grid_position(maze, &mut self.rng);
+                maze.visit_cell(&self.current_cell);
+                self.unvisited_count = maze.cell_count() - 1;
+                self.status = GeneratorStatus::InProgress;
+            }
+            GeneratorStatus::InProgress => {
+                match self.phase {
+                    Phase::Kill => self.kill_process(maze),
+                    Phase::Hunt => self.hunt_process(maze),
+                }
+                if self.unvisited_count == 0 {
+                    self.clear_highlighted(maze);
+                    self.status = GeneratorStatus::Done;
+                }
+            }
+            GeneratorStatus::Done => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &GeneratorStatus {
+        &self.status
+    }
+}
+
+impl HuntAndKill {
+    pub fn new(seed: u64) -> Self {
+        HuntAndKill {
+            current_cell: Coord{ y: 0, x: 0 },
+            unvisited_count: 0,
+            first_unfinished_row: 0,
+            scan_row: 0,
+            highlighted: Vec::new(),
+            phase: Phase::Kill,
+            status: GeneratorStatus::Initialized,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    fn kill_process(&mut self, maze: &mut Maze) {
+        match choose_rand_neighbor(maze, &self.current_cell, false, &mut self.rng) {
+            Ok(direction) => {
+                let next_cell = maze.neighbor(&self.current_cell, direction)
+                    .expect("Unvisited neighbor is outside the maze");
+                self.carve_to(maze, &self.current_cell.clone(), &next_cell, direction);
+            }
+            Err(_) => {
+                self.scan_row = self.first_unfinished_row;
+                self.phase = Phase::Hunt;
+            }
+        }
+    }
+
+    fn hunt_process(&mut self, maze: &mut Maze) {
+        self.clear_highlighted(maze);
+
+        let mut row_finished = true;
+        for x in 0..maze.row_width(self.scan_row) {
+            let cell = Coord{ y: self.scan_row, x };
+            if maze.get_cell_ref(&cell).visited() {
+                continue;
+            }
+            row_finished = false;
+
+            if let Ok(direction) = choose_rand_neighbor(maze, &cell, true, &mut self.rng) {
+                let visited_neighbor = maze.neighbor(&cell, direction)
+                    .expect("Visited neighbor is outside the maze");
+                let back_direction = maze.direction_to(&visited_neighbor, &cell)
+                    .expect("Neighbor does not lead back to the cell");
+                self.clear_highlighted(maze);
+                self.carve_to(maze, &visited_neighbor, &cell, back_direction);
+                self.phase = Phase::Kill;
+                return;
+            }
+
+            maze.change_cell_state(&cell, CellState::Hunt);
+            self.highlighted.push(cell);
+        }
+
+        if row_finished && self.scan_row == self.first_unfinished_row {
+            self.first_unfinished_row += 1;
+        }
+        self.scan_row += 1;
+    }
+
+    fn carve_to(&mut self, maze: &mut Maze, from_cell: &Coord, to_cell: &Coord, direction: usize) {
+        remove_walls_between_cells(maze, from_cell, direction);
+        maze.visit_cell(to_cell);
+        self.unvisited_count -= 1;
+        self.current_cell = *to_cell;
+    }
+
+    fn clear_highlighted(&mut self, maze: &mut Maze) {
+        for cell in self.highlighted.drain(..) {
+            maze.change_cell_state(&cell, CellState::Unvisited);
+        }
+    }
+}
\ No newline at end of file
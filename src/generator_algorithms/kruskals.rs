@@ -0,0 +1,220 @@
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::Coord;
+use crate::structures::topology::Topology;
+use crate::structures::algo_params::{param_number, AlgoParams};
+use crate::generator_algorithms::generator_helpers::{remove_walls_between_cells, GeneratorAlgo, GeneratorStatus};
+
+pub struct Kruskals {
+    edges: Vec<(Coord, usize)>,
+    sets: UnionFind,
+    regions: Vec<Vec<Coord>>,
+    merges_remaining: usize,
+    weave_probability: f64,
+    status: GeneratorStatus,
+    rng: StdRng,
+}
+
+struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+}
+
+impl GeneratorAlgo for Kruskals {
+    fn create_maze(&mut self, maze: &mut Maze) {
+        match self.status {
+            GeneratorStatus::Initialized => {
+                for y in 0..maze.height() {
+                    for x in 0..maze.row_width(y) {
+                        let cell = Coord{ y, x };
+                        for (dir, neighbor) in maze.neighbors(&cell) {
+                            if maze.cell_index(&neighbor) > maze.cell_index(&cell) {
+                                self.edges.push((cell, dir));
+                            }
+                        }
+                    }
+                }
+                self.edges.shuffle(&mut self.rng);
+                self.sets = UnionFind::new(maze.cell_count());
+                self.regions = maze.grid().iter().map(|cell| vec![*cell.coord()]).collect();
+                self.merges_remaining = maze.cell_count() - 1;
+                if maze.topology() == Topology::Square && self.weave_probability > 0.0 {
+                    self.place_crossings(maze);
+                }
+                self.status = GeneratorStatus::InProgress;
+            }
+            GeneratorStatus::InProgress => {
+                while let Some((cell, dir)) = self.edges.pop() {
+                    let neighbor = maze.neighbor(&cell, dir).expect("Neighbor is outside the maze");
+                    if maze.tunnels_under(&cell, dir) || maze.tunnels_under(&neighbor, dir) {
+                        continue;
+                    }
+                    if self.join(maze, &cell, &neighbor) {
+                        remove_walls_between_cells(maze, &cell, dir);
+                        self.merges_remaining -= 1;
+                        break;
+                    }
+                }
+                if self.merges_remaining == 0 || self.edges.is_empty() {
+                    for index in 0..maze.cell_count() {
+                        let coord = *maze.grid()[index].coord();
+                        maze.visit_cell(&coord);
+                    }
+                    self.status = GeneratorStatus::Done;
+                }
+            }
+            GeneratorStatus::Done => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &GeneratorStatus {
+        &self.status
+    }
+}
+
+impl Kruskals {
+    pub fn new(seed: u64, params: &AlgoParams) -> Self {
+        Kruskals {
+            edges: Vec::new(),
+            sets: UnionFind::new(0),
+            regions: Vec::new(),
+            merges_remaining: 0,
+            weave_probability: param_number(params, "weave", 0) as f64 / 100.0,
+            status: GeneratorStatus::Initialized,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Kruskals {
+    // Each set is painted with its root's color, so on a union only the absorbed set needs repainting.
+    fn join(&mut self, maze: &mut Maze, first: &Coord, second: &Coord) -> bool {
+        let first_root = self.sets.find(maze.cell_index(first));
+        let second_root = self.sets.find(maze.cell_index(second));
+        if !self.sets.union(first_root, second_root) {
+            return false;
+        }
+
+        let root = self.sets.find(first_root);
+        let absorbed = if root == first_root { second_root } else { first_root };
+        let absorbed_cells = std::mem::take(&mut self.regions[absorbed]);
+        let color = region_color(root);
+        maze.visit_cell(first);
+        maze.visit_cell(second);
+        for coord in absorbed_cells.iter().chain([first, second]) {
+            maze.paint_cell(coord, &color);
+        }
+        self.regions[root].extend(absorbed_cells);
+        true
+    }
+
+    // Crossings are laid down before any edge is carved, on cells whose neighborhood is still untouched,
+    // so the corridor over the cell and the tunnel under it always join separate sets.
+    fn place_crossings(&mut self, maze: &mut Maze) {
+        for index in 0..maze.cell_count() {
+            let cell = *maze.grid()[index].coord();
+            if !self.rng.gen_bool(self.weave_probability) {
+                continue;
+            }
+            let neighbors: Vec<Coord> = maze.neighbors(&cell).into_iter().map(|(_, neighbor)| neighbor).collect();
+            let untouched = |coord: &Coord| maze.get_cell_ref(coord).walls()[..4].iter().all(|wall| *wall);
+            if neighbors.len() != 4 || !untouched(&cell) || !neighbors.iter().all(untouched) {
+                continue;
+            }
+
+            let over = self.rng.gen_range(0..2);
+            self.join(maze, &cell, &neighbors[over]);
+            self.join(maze, &cell, &neighbors[over + 2]);
+            self.join(maze, &neighbors[1 - over], &neighbors[3 - over]);
+            self.merges_remaining -= 3;
+            remove_walls_between_cells(maze, &cell, over);
+            remove_walls_between_cells(maze, &cell, over + 2);
+            maze.tunnel_under(&neighbors[1 - over], 3 - over);
+        }
+    }
+}
+
+fn region_color(root: usize) -> String {
+    let hue = (root * 137) % 360;
+    format!("hsl({hue}, 65%, 70%)")
+}
+
+impl UnionFind {
+    fn new(size: usize) -> Self {
+        UnionFind {
+            parent: (0..size).collect(),
+            rank: vec![0; size],
+        }
+    }
+
+    fn find(&mut self, id: usize) -> usize {
+        let mut root = id;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut current = id;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    fn union(&mut self, first: usize, second: usize) -> bool {
+        let first_root = self.find(first);
+        let second_root = self.find(second);
+        if first_root == second_root {
+            return false;
+        }
+
+        match self.rank[first_root].cmp(&self.rank[second_root]) {
+            std::cmp::Ordering::Less => self.parent[first_root] = second_root,
+            std::cmp::Ordering::Greater => self.parent[second_root] = first_root,
+            std::cmp::Ordering::Equal => {
+                self.parent[second_root] = first_root;
+                self.rank[first_root] += 1;
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator_algorithms::generator_helpers::tests::{generate, passage_count, reachable_cells};
+
+    #[test]
+    fn carves_a_spanning_tree() {
+        for seed in 0..10 {
+            let mut maze = Maze::new(10, 13);
+            generate(&mut maze, "kruskals", seed);
+            assert_eq!(reachable_cells(&maze, &Coord{ y: 0, x: 0 }).len(), maze.cell_count());
+            assert_eq!(passage_count(&maze), maze.cell_count() - 1);
+        }
+    }
+
+    #[test]
+    fn full_weave_stays_connected() {
+        let params = AlgoParams::from([("weave".to_string(), "100".to_string())]);
+        for seed in 0..10 {
+            let mut maze = Maze::new(12, 12);
+            let mut kruskals = Kruskals::new(seed, &params);
+            while kruskals.status != GeneratorStatus::Done {
+                kruskals.create_maze(&mut maze);
+            }
+            assert!(maze.grid().iter().any(|cell| cell.tunnel().is_some()), "no crossings were placed");
+            assert_eq!(reachable_cells(&maze, &Coord{ y: 0, x: 0 }).len(), maze.cell_count());
+            assert_eq!(passage_count(&maze), maze.cell_count() - 1);
+        }
+    }
+}
\ No newline at end of file
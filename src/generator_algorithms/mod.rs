@@ -2,3 +2,13 @@ pub mod random_prim;
 pub mod recursive_backtracker;
 pub mod generator_helpers;
 pub mod ellers;
+pub mod kruskals;
+pub mod wilsons;
+pub mod aldous_broder;
+pub mod hunt_and_kill;
+pub mod growing_tree;
+pub mod binary_tree;
+pub mod sidewinder;
+pub mod recursive_division;
+pub mod braid;
+pub mod terrain;
\ No newline at end of file
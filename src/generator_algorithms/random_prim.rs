@@ -1,5 +1,5 @@
-use rand::{thread_rng, Rng};
-use dioxus::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
 use indexmap::IndexSet;
 
 use crate::structures::maze::Maze;
@@ -9,22 +9,21 @@ use crate::generator_algorithms::generator_helpers::{GeneratorStatus, random_gri
 pub struct RandomPrim {
     frontier: IndexSet<Coord>,
     status: GeneratorStatus,
+    rng: StdRng,
 }
 
 impl GeneratorAlgo for RandomPrim {
-    fn create_maze(&mut self, maze: &mut Signal<Maze>) {
-        let maze: &mut Maze = &mut maze.write();
-
+    fn create_maze(&mut self, maze: &mut Maze) {
         match self.status {
             GeneratorStatus::Initialized => {
-                let start: Coord = random_grid_position(maze);
+                let start: Coord = random_grid_position(maze, &mut self.rng);
                 maze.visit_cell(&start);
                 self.add_cells_to_frontier(maze, &start);
                 self.status = GeneratorStatus::InProgress;
             }
             GeneratorStatus::InProgress => {
                 let rand_frontier = self.rand_frontier();
-                let direction_of_rand_visited_neighbor: usize = choose_rand_neighbor(maze, &rand_frontier, true).unwrap();
+                let direction_of_rand_visited_neighbor: usize = choose_rand_neighbor(maze, &rand_frontier, true, &mut self.rng).unwrap();
                 remove_walls_between_cells(maze, &rand_frontier, direction_of_rand_visited_neighbor);
                 maze.visit_cell(&rand_frontier);
                 self.add_cells_to_frontier(maze, &rand_frontier);
@@ -44,30 +43,21 @@ impl GeneratorAlgo for RandomPrim {
 }
 
 impl RandomPrim {
-    pub fn new() -> Self {
+    pub fn new(seed: u64) -> Self {
         let frontier = IndexSet::new();
 
         RandomPrim {
             frontier,
             status: GeneratorStatus::Initialized,
+            rng: StdRng::seed_from_u64(seed),
         }
     }
 
     fn add_cells_to_frontier(&mut self, maze: &mut Maze, origin: &Coord) {
-        let mut new_frontier_cells: Vec<Coord> = Vec::new();
-
-        if origin.y > 0 && !maze.get_cell_ref(&Coord{ y: origin.y - 1, x: origin.x }).visited() {
-            new_frontier_cells.push(Coord{ y: origin.y - 1, x: origin.x })
-        }
-        if origin.y < maze.height() - 1 && !maze.get_cell_ref(&Coord{ y: origin.y + 1, x: origin.x }).visited() {
-            new_frontier_cells.push(Coord{ y: origin.y + 1, x: origin.x });
-        }
-        if origin.x > 0 && !maze.get_cell_ref(&Coord{ y: origin.y, x: origin.x - 1 }).visited() {
-            new_frontier_cells.push(Coord{ y: origin.y, x: origin.x - 1 });
-        }
-        if origin.x < maze.width() - 1 && !maze.get_cell_ref(&Coord{ y: origin.y, x: origin.x + 1 }).visited() {
-            new_frontier_cells.push(Coord{ y: origin.y, x: origin.x + 1 });
-        }
+        let new_frontier_cells: Vec<Coord> = maze.neighbors(origin).into_iter()
+            .map(|(_, neighbor)| neighbor)
+            .filter(|neighbor| !maze.get_cell_ref(neighbor).visited())
+            .collect();
 
         for cell in new_frontier_cells {
             self.frontier.insert(cell);
@@ -75,6 +65,6 @@ impl RandomPrim {
     }
 
     fn rand_frontier (&mut self) -> Coord {
-        self.frontier.swap_remove_index(thread_rng().gen_range(0..self.frontier.len())).unwrap()
+        self.frontier.swap_remove_index(self.rng.gen_range(0..self.frontier.len())).unwrap()
     }
 }
\ No newline at end of file
@@ -1,21 +1,24 @@
-use dioxus::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
 
 use crate::structures::maze::Maze;
 use crate::structures::cell::{Coord};
+use crate::structures::algo_params::{param_number, AlgoParams};
 use crate::generator_algorithms::generator_helpers::{choose_rand_neighbor, random_grid_position, remove_walls_between_cells, GeneratorAlgo, GeneratorStatus};
 
 pub struct RecursiveBacktracker {
     stack: Vec<Coord>,
+    weave_probability: f64,
     status: GeneratorStatus,
+    rng: StdRng,
 }
 
 impl GeneratorAlgo for RecursiveBacktracker {
-    fn create_maze(&mut self, maze: &mut Signal<Maze>) {
-        let maze: &mut Maze = &mut maze.write();
-
+    fn create_maze(&mut self, maze: &mut Maze) {
         match self.status {
             GeneratorStatus::Initialized => {
-                let start: Coord = random_grid_position(maze);
+                let start: Coord = random_grid_position(maze, &mut self.rng);
                 maze.visit_cell(&start);
                 self.stack.push(start);
                 self.status = GeneratorStatus::InProgress;
@@ -26,13 +29,14 @@ impl GeneratorAlgo for RecursiveBacktracker {
                         Some(coord) => coord,
                         None => panic!("why isn't there a value here?")
                     };
-                    if let Ok(dir) = choose_rand_neighbor(maze, &current_cell, false) { match dir {
-                            0 => self.next_cell(maze, current_cell, Coord{ x: current_cell.x, y: current_cell.y - 1 }, dir),
-                            1 => self.next_cell(maze, current_cell, Coord{ x: current_cell.x + 1, y: current_cell.y }, dir),
-                            2 => self.next_cell(maze, current_cell, Coord{ x: current_cell.x, y: current_cell.y + 1 }, dir),
-                            _ => self.next_cell(maze, current_cell, Coord{ x: current_cell.x - 1, y: current_cell.y }, dir),
-                        }
-                    } }
+                    if let Ok(dir) = choose_rand_neighbor(maze, &current_cell, false, &mut self.rng) {
+                        let next_cell = maze.neighbor(&current_cell, dir).expect("Neighbor is outside the maze");
+                        self.next_cell(maze, current_cell, next_cell, dir);
+                    }
+                    else if let Some(dir) = self.choose_tunnel(maze, &current_cell) {
+                        self.tunnel(maze, current_cell, dir);
+                    }
+                }
                 else {
                     self.status = GeneratorStatus::Done;
                 }
@@ -49,12 +53,14 @@ impl GeneratorAlgo for RecursiveBacktracker {
 }
 
 impl RecursiveBacktracker {
-    pub fn new() -> Self {
+    pub fn new(seed: u64, params: &AlgoParams) -> Self {
         let stack: Vec<Coord> = Vec::new();
 
         RecursiveBacktracker {
             stack,
+            weave_probability: param_number(params, "weave", 0) as f64 / 100.0,
             status: GeneratorStatus::Initialized,
+            rng: StdRng::seed_from_u64(seed),
         }
     }
 
@@ -64,4 +70,24 @@ impl RecursiveBacktracker {
         maze.visit_cell(&next_cell);
         self.stack.push(next_cell);
     }
+
+    // Only tried once the cell has no unvisited neighbors, so a weave of 0 carves the same maze as before.
+    fn choose_tunnel(&mut self, maze: &Maze, cell: &Coord) -> Option<usize> {
+        if self.weave_probability == 0.0 {
+            return None;
+        }
+        let mut directions: Vec<usize> = (0..maze.direction_count(cell)).collect();
+        directions.shuffle(&mut self.rng);
+        directions.into_iter()
+            .filter(|direction| maze.tunnel_target(cell, *direction).is_some_and(|target| !maze.get_cell_ref(&target).visited()))
+            .find(|_| self.rng.gen_bool(self.weave_probability))
+    }
+
+    fn tunnel(&mut self, maze: &mut Maze, current_cell: Coord, dir: usize) {
+        let next_cell = maze.tunnel_target(&current_cell, dir).expect("Tunnel target is outside the maze");
+        self.stack.push(current_cell);
+        maze.tunnel_under(&current_cell, dir);
+        maze.visit_cell(&next_cell);
+        self.stack.push(next_cell);
+    }
 }
\ No newline at end of file
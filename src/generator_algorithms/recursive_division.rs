@@ -0,0 +1,130 @@
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::Coord;
+use crate::generator_algorithms::generator_helpers::{add_walls_between_cells, GeneratorAlgo, GeneratorStatus};
+
+pub struct RecursiveDivision {
+    chambers: Vec<Chamber>,
+    status: GeneratorStatus,
+    rng: StdRng,
+}
+
+#[derive(Clone, Copy)]
+struct Chamber {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+impl GeneratorAlgo for RecursiveDivision {
+    fn create_maze(&mut self, maze: &mut Maze) {
+        match self.status {
+            GeneratorStatus::Initialized => {
+                maze.remove_interior_walls();
+                for y in 0..maze.height() {
+                    for x in 0..maze.width() {
+                        maze.visit_cell(&Coord{ y, x });
+                    }
+                }
+                self.chambers.push(Chamber{ x: 0, y: 0, width: maze.width(), height: maze.height() });
+                self.status = GeneratorStatus::InProgress;
+            }
+            GeneratorStatus::InProgress => {
+                while let Some(chamber) = self.chambers.pop() {
+                    if chamber.width > 1 && chamber.height > 1 {
+                        self.divide_chamber(maze, chamber);
+                        break;
+                    }
+                }
+                if self.chambers.is_empty() {
+                    self.status = GeneratorStatus::Done;
+                }
+            }
+            GeneratorStatus::Done => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &GeneratorStatus {
+        &self.status
+    }
+}
+
+impl RecursiveDivision {
+    pub fn new(seed: u64) -> Self {
+        RecursiveDivision {
+            chambers: Vec::new(),
+            status: GeneratorStatus::Initialized,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    fn divide_chamber(&mut self, maze: &mut Maze, chamber: Chamber) {
+        let orientation = if chamber.width < chamber.height {
+            Orientation::Horizontal
+        }
+        else if chamber.height < chamber.width {
+            Orientation::Vertical
+        }
+        else if self.rng.gen_bool(0.5) {
+            Orientation::Horizontal
+        }
+        else {
+            Orientation::Vertical
+        };
+
+        match orientation {
+            Orientation::Horizontal => {
+                let wall_row = self.rng.gen_range(chamber.y..chamber.y + chamber.height - 1);
+                let passage_col = self.rng.gen_range(chamber.x..chamber.x + chamber.width);
+                for x in chamber.x..chamber.x + chamber.width {
+                    if x != passage_col {
+                        add_walls_between_cells(maze, &Coord{ y: wall_row, x }, 2);
+                    }
+                }
+                self.chambers.push(Chamber{
+                    x: chamber.x,
+                    y: chamber.y,
+                    width: chamber.width,
+                    height: wall_row - chamber.y + 1,
+                });
+                self.chambers.push(Chamber{
+                    x: chamber.x,
+                    y: wall_row + 1,
+                    width: chamber.width,
+                    height: chamber.y + chamber.height - wall_row - 1,
+                });
+            }
+            Orientation::Vertical => {
+                let wall_col = self.rng.gen_range(chamber.x..chamber.x + chamber.width - 1);
+                let passage_row = self.rng.gen_range(chamber.y..chamber.y + chamber.height);
+                for y in chamber.y..chamber.y + chamber.height {
+                    if y != passage_row {
+                        add_walls_between_cells(maze, &Coord{ y, x: wall_col }, 1);
+                    }
+                }
+                self.chambers.push(Chamber{
+                    x: chamber.x,
+                    y: chamber.y,
+                    width: wall_col - chamber.x + 1,
+                    height: chamber.height,
+                });
+                self.chambers.push(Chamber{
+                    x: wall_col + 1,
+                    y: chamber.y,
+                    width: chamber.x + chamber.width - wall_col - 1,
+                    height: chamber.height,
+                });
+            }
+        }
+    }
+}
\ No newline at end of file
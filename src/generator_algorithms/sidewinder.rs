@@ -0,0 +1,110 @@
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::Coord;
+use crate::structures::algo_params::{param_choice, AlgoParam, AlgoParamKind, AlgoParams};
+use crate::generator_algorithms::generator_helpers::{remove_walls_between_cells, GeneratorAlgo, GeneratorStatus};
+
+pub struct Sidewinder {
+    rows_processed: usize,
+    current_col: usize,
+    run: Vec<Coord>,
+    vertical_direction: usize,
+    run_direction: usize,
+    status: GeneratorStatus,
+    rng: StdRng,
+}
+
+impl GeneratorAlgo for Sidewinder {
+    fn create_maze(&mut self, maze: &mut Maze) {
+        match self.status {
+            GeneratorStatus::Initialized => {
+                self.process_cell(maze);
+                self.status = GeneratorStatus::InProgress;
+            }
+            GeneratorStatus::InProgress => {
+                self.process_cell(maze);
+                if self.rows_processed == maze.height() {
+                    self.status = GeneratorStatus::Done;
+                }
+            }
+            GeneratorStatus::Done => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &GeneratorStatus {
+        &self.status
+    }
+}
+
+impl Sidewinder {
+    pub fn new(seed: u64, params: &AlgoParams) -> Self {
+        let (vertical_direction, run_direction) = match param_choice(params, "bias", "north_east") {
+            "north_west" => (0, 3),
+            "south_east" => (2, 1),
+            "south_west" => (2, 3),
+            _ => (0, 1),
+        };
+
+        Sidewinder {
+            rows_processed: 0,
+            current_col: 0,
+            run: Vec::new(),
+            vertical_direction,
+            run_direction,
+            status: GeneratorStatus::Initialized,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    // Rows are processed starting from the biased side, and each row is walked in the run direction.
+    fn process_cell(&mut self, maze: &mut Maze) {
+        let current_row = if self.vertical_direction == 0 { self.rows_processed } else { maze.height() - 1 - self.rows_processed };
+        let current_col = if self.run_direction == 1 { self.current_col } else { maze.width() - 1 - self.current_col };
+        let current_cell = Coord{ y: current_row, x: current_col };
+        maze.visit_cell(&current_cell);
+        self.run.push(current_cell);
+
+        let at_run_edge = self.current_col == maze.width() - 1;
+        let in_corridor_row = self.rows_processed == 0;
+
+        if at_run_edge || (!in_corridor_row && self.rng.gen_bool(0.5)) {
+            if !in_corridor_row {
+                let run_cell = *self.run.choose(&mut self.rng).expect("Run is empty");
+                remove_walls_between_cells(maze, &run_cell, self.vertical_direction);
+            }
+            self.run.clear();
+        }
+        else {
+            remove_walls_between_cells(maze, &current_cell, self.run_direction);
+        }
+
+        self.current_col += 1;
+        if self.current_col == maze.width() {
+            self.current_col = 0;
+            self.rows_processed += 1;
+        }
+    }
+}
+
+pub fn get_sidewinder_params() -> Vec<AlgoParam> {
+    vec![
+        AlgoParam {
+            id: "bias".to_string(),
+            label: "Bias".to_string(),
+            kind: AlgoParamKind::Choice {
+                options: vec![
+                    ("north_east".to_string(), "North-East".to_string()),
+                    ("north_west".to_string(), "North-West".to_string()),
+                    ("south_east".to_string(), "South-East".to_string()),
+                    ("south_west".to_string(), "South-West".to_string()),
+                ],
+                default: "north_east".to_string(),
+            },
+        },
+    ]
+}
\ No newline at end of file
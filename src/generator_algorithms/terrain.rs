@@ -0,0 +1,41 @@
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+use crate::generator_algorithms::generator_helpers::random_grid_position;
+use crate::structures::maze::Maze;
+use crate::structures::cell::{Coord, Terrain};
+
+const PATCH_SIZE: usize = 6;
+
+pub fn get_terrain_options() -> Vec<(String, String)> {
+    vec![
+        ("plain".to_string(), "Plain (cost 2)".to_string()),
+        ("road".to_string(), "Road (cost 1)".to_string()),
+        ("mud".to_string(), "Mud (cost 5)".to_string()),
+        ("water".to_string(), "Water (cost 10)".to_string()),
+    ]
+}
+
+pub fn scatter_terrain(maze: &mut Maze, seed: u64, terrain_percent: usize) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let patch_count = maze.cell_count() * terrain_percent / 100 / PATCH_SIZE;
+
+    clear_terrain(maze);
+    for _ in 0..patch_count {
+        let terrain = *[Terrain::Road, Terrain::Mud, Terrain::Water].choose(&mut rng).unwrap();
+        let mut cell = random_grid_position(maze, &mut rng);
+        for _ in 0..PATCH_SIZE {
+            maze.set_cell_terrain(&cell, terrain);
+            let direction = rng.gen_range(0..maze.direction_count(&cell));
+            cell = maze.neighbor(&cell, direction).unwrap_or(cell);
+        }
+    }
+}
+
+pub fn clear_terrain(maze: &mut Maze) {
+    for y in 0..maze.height() {
+        for x in 0..maze.row_width(y) {
+            maze.set_cell_terrain(&Coord{ y, x }, Terrain::Plain);
+        }
+    }
+}
\ No newline at end of file
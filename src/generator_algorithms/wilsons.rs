@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use indexmap::IndexSet;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::{CellState, Coord};
+use crate::generator_algorithms::generator_helpers::{choose_rand_direction, random_grid_position, remove_walls_between_cells, GeneratorAlgo, GeneratorStatus};
+
+pub struct Wilsons {
+    unvisited: IndexSet<Coord>,
+    walk: Vec<Coord>,
+    walk_directions: Vec<usize>,
+    walk_positions: HashMap<Coord, usize>,
+    status: GeneratorStatus,
+    rng: StdRng,
+}
+
+impl GeneratorAlgo for Wilsons {
+    fn create_maze(&mut self, maze: &mut Maze) {
+        match self.status {
+            GeneratorStatus::Initialized => {
+                for y in 0..maze.height() {
+                    for x in 0..maze.row_width(y) {
+                        self.unvisited.insert(Coord{ y, x });
+                    }
+                }
+                let start: Coord = random_grid_position(maze, &mut self.rng);
+                maze.visit_cell(&start);
+                self.unvisited.swap_remove(&start);
+                self.status = GeneratorStatus::InProgress;
+            }
+            GeneratorStatus::InProgress => {
+                if self.walk.is_empty() {
+                    self.start_walk(maze);
+                }
+                else {
+                    self.extend_walk(maze);
+                }
+                if self.unvisited.is_empty() {
+                    self.status = GeneratorStatus::Done;
+                }
+            }
+            GeneratorStatus::Done => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &GeneratorStatus {
+        &self.status
+    }
+}
+
+impl Wilsons {
+    pub fn new(seed: u64) -> Self {
+        Wilsons {
+            unvisited: IndexSet::new(),
+            walk: Vec::new(),
+            walk_directions: Vec::new(),
+            walk_positions: HashMap::new(),
+            status: GeneratorStatus::Initialized,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    fn start_walk(&mut self, maze: &mut Maze) {
+        let start = self.unvisited[self.rng.gen_range(0..self.unvisited.len())];
+        maze.change_cell_state(&start, CellState::Walk);
+        self.walk_positions.insert(start, 0);
+        self.walk.push(start);
+    }
+
+    fn extend_walk(&mut self, maze: &mut Maze) {
+        let current_cell = *self.walk.last().expect("Walk is empty");
+        let direction = choose_rand_direction(maze, &current_cell, &mut self.rng);
+        let next_cell = maze.neighbor(&current_cell, direction)
+            .expect("Random direction has no neighbor");
+
+        if maze.get_cell_ref(&next_cell).visited() {
+            self.walk_directions.push(direction);
+            self.carve_walk(maze);
+        }
+        else if let Some(&position) = self.walk_positions.get(&next_cell) {
+            self.erase_loop(maze, position);
+        }
+        else {
+            maze.change_cell_state(&next_cell, CellState::Walk);
+            self.walk_directions.push(direction);
+            self.walk_positions.insert(next_cell, self.walk.len());
+            self.walk.push(next_cell);
+        }
+    }
+
+    fn erase_loop(&mut self, maze: &mut Maze, position: usize) {
+        for cell in self.walk.drain(position + 1..) {
+            maze.change_cell_state(&cell, CellState::Unvisited);
+            self.walk_positions.remove(&cell);
+        }
+        self.walk_directions.truncate(position);
+    }
+
+    fn carve_walk(&mut self, maze: &mut Maze) {
+        for (cell, direction) in self.walk.iter().zip(self.walk_directions.iter()) {
+            remove_walls_between_cells(maze, cell, *direction);
+            maze.visit_cell(cell);
+            self.unvisited.swap_remove(cell);
+        }
+        self.walk.clear();
+        self.walk_directions.clear();
+        self.walk_positions.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::generator_algorithms::generator_helpers::tests::{passage_count, reachable_cells};
+
+    #[test]
+    fn walk_erases_loops_and_carves_no_cycles() {
+        for seed in 0..10 {
+            let mut maze = Maze::new(9, 11);
+            let mut wilsons = Wilsons::new(seed);
+            while wilsons.status != GeneratorStatus::Done {
+                wilsons.create_maze(&mut maze);
+
+                let distinct: HashSet<&Coord> = wilsons.walk.iter().collect();
+                assert_eq!(distinct.len(), wilsons.walk.len(), "walk still contains a loop");
+                assert_eq!(wilsons.walk_directions.len(), wilsons.walk.len().saturating_sub(1));
+
+                let visited = maze.grid().iter().filter(|cell| cell.visited()).count();
+                assert_eq!(passage_count(&maze) + 1, visited, "carved passages contain a cycle");
+            }
+            assert_eq!(reachable_cells(&maze, &Coord{ y: 0, x: 0 }).len(), maze.cell_count());
+        }
+    }
+}
\ No newline at end of file
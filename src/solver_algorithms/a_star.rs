@@ -0,0 +1,193 @@
+use std::collections::{HashMap, HashSet, BinaryHeap};
+use std::cmp::Ordering;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::{CellState, Coord};
+use crate::solver_algorithms::solver_helpers::{open_neighbors, reset_solver, step_cost, Heuristic, SolverAlgo, SolverStatus};
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct EstimatedCost {
+    cell_coord: Coord,
+    distance: usize,
+    estimate: usize,
+}
+
+impl Ord for EstimatedCost {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+            .then_with(|| self.distance.cmp(&other.distance))
+    }
+}
+
+impl PartialOrd for EstimatedCost {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub struct AStar {
+    start: Coord,
+    finish: Coord,
+    heuristic: Heuristic,
+    min_cost: usize,
+    explored: HashMap<Coord, Coord>,
+    distances: HashMap<Coord, usize>,
+    closed: HashSet<Coord>,
+    frontier: BinaryHeap<EstimatedCost>,
+    current_cell: Coord,
+    cells_expanded: usize,
+    status: SolverStatus,
+}
+
+impl SolverAlgo for AStar {
+    fn find_solution(&mut self, maze: &mut Maze) {
+        match self.status {
+            SolverStatus::Initialized => {
+                maze.change_cell_state(&self.start, CellState::Start);
+                maze.change_cell_state(&self.finish, CellState::Finish);
+                self.min_cost = maze.grid().iter().map(|cell| cell.cost()).min().unwrap_or(1);
+                self.distances.insert(self.start, 0);
+                self.frontier.push(EstimatedCost {
+                    cell_coord: self.start,
+                    distance: 0,
+                    estimate: self.estimate(maze, &self.start),
+                });
+                self.status = SolverStatus::InProgress;
+            }
+            SolverStatus::InProgress => {
+                let next: EstimatedCost = match self.frontier.pop() {
+                    Some(next) => next,
+                    None => {
+                        self.status = SolverStatus::Failed;
+                        return;
+                    }
+                };
+                if !self.closed.insert(next.cell_coord) {
+                    return;
+                }
+                self.current_cell = next.cell_coord;
+                self.cells_expanded += 1;
+
+                if self.current_cell == self.finish {
+                    if self.finish == self.start {
+                        self.status = SolverStatus::Done;
+                        return;
+                    }
+                    self.current_cell = *self.explored.get(&self.finish).unwrap();
+                    self.status = SolverStatus::Solved;
+                }
+                else {
+                    self.add_adjacent_cells_to_frontier(maze, next.distance);
+                }
+            }
+            SolverStatus::Solved => {
+                if self.current_cell == self.start {
+                    self.status = SolverStatus::Done;
+                    return;
+                }
+                maze.change_cell_state(&self.current_cell, CellState::Solution);
+                self.current_cell = *self.explored.get(&self.current_cell).unwrap();
+                if self.current_cell == self.start {
+                    self.status = SolverStatus::Done;
+                }
+            }
+            SolverStatus::Done | SolverStatus::Failed => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &SolverStatus {
+        &self.status
+    }
+
+    fn reset(&self, maze: &mut Maze) {
+        reset_solver(maze);
+    }
+
+    fn cells_expanded(&self) -> usize {
+        self.cells_expanded
+    }
+}
+
+impl AStar {
+    pub fn new(start: &Coord, finish: &Coord, heuristic: Heuristic) -> Self {
+        AStar {
+            start: *start,
+            finish: *finish,
+            heuristic,
+            min_cost: 1,
+            explored: HashMap::new(),
+            distances: HashMap::new(),
+            closed: HashSet::new(),
+            frontier: BinaryHeap::new(),
+            current_cell: *start,
+            cells_expanded: 0,
+            status: SolverStatus::Initialized,
+        }
+    }
+
+    fn add_adjacent_cells_to_frontier(&mut self, maze: &mut Maze, distance: usize) {
+        for new_frontier_cell in open_neighbors(maze, &self.current_cell) {
+            let new_distance = distance + step_cost(maze, &self.current_cell, &new_frontier_cell);
+            self.process_frontier_cell(maze, new_frontier_cell, new_distance);
+        }
+    }
+
+    fn estimate(&self, maze: &Maze, cell: &Coord) -> usize {
+        self.heuristic.estimate(maze, cell, &self.finish) * self.min_cost
+    }
+
+    fn process_frontier_cell(&mut self, maze: &mut Maze, new_frontier_cell: Coord, distance: usize) {
+        if self.closed.contains(&new_frontier_cell) {
+            return;
+        }
+        if let Some(known_distance) = self.distances.get(&new_frontier_cell) {
+            if *known_distance <= distance {
+                return;
+            }
+        }
+
+        self.distances.insert(new_frontier_cell, distance);
+        self.explored.insert(new_frontier_cell, self.current_cell);
+        self.frontier.push(EstimatedCost {
+            cell_coord: new_frontier_cell,
+            distance,
+            estimate: distance + self.estimate(maze, &new_frontier_cell),
+        });
+        if new_frontier_cell != self.start && new_frontier_cell != self.finish {
+            maze.change_cell_state(&new_frontier_cell, CellState::Frontier);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator_algorithms::braid::tests::braided;
+    use crate::solver_algorithms::solver_helpers::tests::{route_length, solve};
+
+    #[test]
+    fn finds_the_shortest_route_through_loops() {
+        let (start, finish) = (Coord{ y: 0, x: 0 }, Coord{ y: 8, x: 11 });
+        for seed in 0..10 {
+            let mut a_star_maze = braided(seed, 100);
+            let mut bfs_maze = braided(seed, 100);
+            solve(&mut a_star_maze, "a_star", &start, &finish);
+            solve(&mut bfs_maze, "breadth_first_search", &start, &finish);
+            assert_eq!(route_length(&a_star_maze), route_length(&bfs_maze));
+        }
+    }
+
+    #[test]
+    fn manhattan_expands_no_more_cells_than_dijkstra() {
+        let (start, finish) = (Coord{ y: 0, x: 0 }, Coord{ y: 8, x: 11 });
+        for seed in 0..10 {
+            let mut a_star_maze = braided(seed, 100);
+            let mut dijkstra_maze = braided(seed, 100);
+            let a_star = solve(&mut a_star_maze, "a_star", &start, &finish);
+            let dijkstra = solve(&mut dijkstra_maze, "djikstras", &start, &finish);
+            assert!(a_star.cells_expanded() <= dijkstra.cells_expanded());
+        }
+    }
+}
\ No newline at end of file
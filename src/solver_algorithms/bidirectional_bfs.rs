@@ -0,0 +1,139 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::{CellState, Coord};
+use crate::solver_algorithms::solver_helpers::{open_neighbors, reset_solver, SolverAlgo, SolverStatus};
+
+pub struct BidirectionalBfs {
+    start: Coord,
+    finish: Coord,
+    start_frontier: VecDeque<Coord>,
+    finish_frontier: VecDeque<Coord>,
+    start_explored: HashMap<Coord, Coord>,
+    finish_explored: HashMap<Coord, Coord>,
+    expand_start_side: bool,
+    solution: Vec<Coord>,
+    cells_expanded: usize,
+    status: SolverStatus,
+}
+
+impl SolverAlgo for BidirectionalBfs {
+    fn find_solution(&mut self, maze: &mut Maze) {
+        match self.status {
+            SolverStatus::Initialized => {
+                maze.change_cell_state(&self.start, CellState::Start);
+                maze.change_cell_state(&self.finish, CellState::Finish);
+                self.start_explored.insert(self.start, self.start);
+                self.finish_explored.insert(self.finish, self.finish);
+                self.start_frontier.push_back(self.start);
+                self.finish_frontier.push_back(self.finish);
+                self.status = if self.start == self.finish { SolverStatus::Done } else { SolverStatus::InProgress };
+            }
+            SolverStatus::InProgress => {
+                if self.start_frontier.is_empty() || self.finish_frontier.is_empty() {
+                    self.status = SolverStatus::Failed;
+                    return;
+                }
+                let expand_start_side = self.expand_start_side;
+                self.expand_start_side = !self.expand_start_side;
+                self.expand_side(maze, expand_start_side);
+            }
+            SolverStatus::Solved => {
+                if let Some(cell) = self.solution.pop() {
+                    maze.change_cell_state(&cell, CellState::Solution);
+                }
+                if self.solution.is_empty() {
+                    self.status = SolverStatus::Done;
+                }
+            }
+            SolverStatus::Done | SolverStatus::Failed => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &SolverStatus {
+        &self.status
+    }
+
+    fn reset(&self, maze: &mut Maze) {
+        reset_solver(maze);
+    }
+
+    fn cells_expanded(&self) -> usize {
+        self.cells_expanded
+    }
+}
+
+impl BidirectionalBfs {
+    pub fn new(start: &Coord, finish: &Coord) -> Self {
+        BidirectionalBfs {
+            start: *start,
+            finish: *finish,
+            start_frontier: VecDeque::new(),
+            finish_frontier: VecDeque::new(),
+            start_explored: HashMap::new(),
+            finish_explored: HashMap::new(),
+            expand_start_side: true,
+            solution: Vec::new(),
+            cells_expanded: 0,
+            status: SolverStatus::Initialized,
+        }
+    }
+
+    fn expand_side(&mut self, maze: &mut Maze, start_side: bool) {
+        let (frontier, explored, other_explored, frontier_state) = if start_side {
+            (&mut self.start_frontier, &mut self.start_explored, &self.finish_explored, CellState::StartFrontier)
+        }
+        else {
+            (&mut self.finish_frontier, &mut self.finish_explored, &self.start_explored, CellState::FinishFrontier)
+        };
+
+        let current_cell = frontier.pop_front().unwrap();
+        self.cells_expanded += 1;
+
+        let mut meeting_cell: Option<Coord> = None;
+        for neighbor in open_neighbors(maze, &current_cell) {
+            if explored.contains_key(&neighbor) {
+                continue;
+            }
+            if other_explored.contains_key(&neighbor) {
+                meeting_cell = Some(neighbor);
+                break;
+            }
+
+            explored.insert(neighbor, current_cell);
+            frontier.push_back(neighbor);
+            if neighbor != self.start && neighbor != self.finish {
+                maze.change_cell_state(&neighbor, frontier_state);
+            }
+        }
+
+        if let Some(meeting_cell) = meeting_cell {
+            self.build_solution(&current_cell, &meeting_cell, start_side);
+            self.status = if self.solution.is_empty() { SolverStatus::Done } else { SolverStatus::Solved };
+        }
+    }
+
+    fn build_solution(&mut self, current_cell: &Coord, neighbor: &Coord, start_side: bool) {
+        let (start_side_cell, finish_side_cell) = if start_side {
+            (*current_cell, *neighbor)
+        }
+        else {
+            (*neighbor, *current_cell)
+        };
+
+        let mut cell = start_side_cell;
+        while cell != self.start {
+            self.solution.push(cell);
+            cell = *self.start_explored.get(&cell).unwrap();
+        }
+        self.solution.reverse();
+
+        let mut cell = finish_side_cell;
+        while cell != self.finish {
+            self.solution.push(cell);
+            cell = *self.finish_explored.get(&cell).unwrap();
+        }
+    }
+}
\ No newline at end of file
@@ -1,10 +1,8 @@
 use std::collections::{HashMap, VecDeque};
 
-use dioxus::prelude::*;
-
 use crate::structures::maze::Maze;
 use crate::structures::cell::{CellState, Coord};
-use crate::solver_algorithms::solver_helpers::{reset_solver, solved, SolverAlgo, SolverStatus};
+use crate::solver_algorithms::solver_helpers::{open_neighbors, reset_solver, solved, SolverAlgo, SolverStatus};
 
 pub struct BreadthFirstSearch {
     start: Coord,
@@ -12,13 +10,12 @@ pub struct BreadthFirstSearch {
     frontier: VecDeque<Coord>,
     explored: HashMap<Coord, Coord>,
     current_cell: Coord,
+    cells_expanded: usize,
     status: SolverStatus,
 }
 
 impl SolverAlgo for BreadthFirstSearch {
-    fn find_solution(&mut self, maze: &mut Signal<Maze>) {
-        let maze: &mut Maze = &mut maze.write();
-
+    fn find_solution(&mut self, maze: &mut Maze) {
         match self.status {
             SolverStatus::Initialized => {
                 maze.change_cell_state(&self.start, CellState::Start);
@@ -27,7 +24,14 @@ impl SolverAlgo for BreadthFirstSearch {
                 self.status = SolverStatus::InProgress;
             }
             SolverStatus::InProgress => {
-                self.current_cell = self.frontier.pop_front().unwrap();
+                self.current_cell = match self.frontier.pop_front() {
+                    Some(cell) => cell,
+                    None => {
+                        self.status = SolverStatus::Failed;
+                        return;
+                    }
+                };
+                self.cells_expanded += 1;
                 self.add_adjacent_cells_to_frontier(maze);
                 if solved(&self.explored, &self.finish) {
                     self.current_cell = *self.explored.get(&self.finish).unwrap();
@@ -41,7 +45,7 @@ impl SolverAlgo for BreadthFirstSearch {
                     self.status = SolverStatus::Done;
                 }
             }
-            SolverStatus::Done => {
+            SolverStatus::Done | SolverStatus::Failed => {
                 panic!("You shouldn't be here");
             }
         }
@@ -51,10 +55,13 @@ impl SolverAlgo for BreadthFirstSearch {
         &self.status
     }
 
-    fn reset(&self, maze: &mut Signal<Maze>) {
-        let maze = &mut maze.write();
+    fn reset(&self, maze: &mut Maze) {
         reset_solver(maze);
     }
+
+    fn cells_expanded(&self) -> usize {
+        self.cells_expanded
+    }
 }
 
 impl BreadthFirstSearch {
@@ -65,28 +72,16 @@ impl BreadthFirstSearch {
             frontier: VecDeque::new(),
             explored: HashMap::new(),
             current_cell: *start,
+            cells_expanded: 0,
             status: SolverStatus::Initialized,
         }
     }
 
     fn add_adjacent_cells_to_frontier(&mut self, maze: &mut Maze) {
-        let cell = *maze.get_cell_ref(&self.current_cell);
-
-        if !cell.walls()[0] && !self.explored.contains_key(&Coord{ y: cell.coord().y - 1, x: cell.coord().x }) {
-            let new_frontier_cell = Coord{ y: cell.coord().y - 1, x: cell.coord().x };
-            self.process_frontier_cell(maze, new_frontier_cell);
-        }
-        if !cell.walls()[1] && !self.explored.contains_key(&Coord{ y: cell.coord().y, x: cell.coord().x + 1 }) {
-            let new_frontier_cell = Coord{ y: cell.coord().y, x: cell.coord().x + 1 };
-            self.process_frontier_cell(maze, new_frontier_cell);
-        }
-        if !cell.walls()[2] && !self.explored.contains_key(&Coord{ y: cell.coord().y + 1, x: cell.coord().x }) {
-            let new_frontier_cell = Coord{ y: cell.coord().y + 1, x: cell.coord().x };
-            self.process_frontier_cell(maze, new_frontier_cell);
-        }
-        if !cell.walls()[3] && !self.explored.contains_key(&Coord{ y: cell.coord().y, x: cell.coord().x - 1 }) {
-            let new_frontier_cell = Coord{ y: cell.coord().y, x: cell.coord().x - 1 };
-            self.process_frontier_cell(maze, new_frontier_cell);
+        for new_frontier_cell in open_neighbors(maze, &self.current_cell) {
+            if !self.explored.contains_key(&new_frontier_cell) {
+                self.process_frontier_cell(maze, new_frontier_cell);
+            }
         }
     }
 
@@ -0,0 +1,258 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::{CellState, Coord};
+use crate::solver_algorithms::solver_helpers::{open_neighbors, reset_solver, unfilled_route, SolverAlgo, SolverStatus};
+
+pub struct CulDeSacFilling {
+    start: Coord,
+    finish: Coord,
+    cul_de_sacs: Vec<Coord>,
+    solution: Vec<Coord>,
+    cells_expanded: usize,
+    status: SolverStatus,
+}
+
+impl SolverAlgo for CulDeSacFilling {
+    fn find_solution(&mut self, maze: &mut Maze) {
+        match self.status {
+            SolverStatus::Initialized => {
+                maze.change_cell_state(&self.start, CellState::Start);
+                maze.change_cell_state(&self.finish, CellState::Finish);
+                if self.start == self.finish {
+                    self.status = SolverStatus::Done;
+                    return;
+                }
+                self.cul_de_sacs = self.find_cul_de_sacs(maze);
+                self.status = SolverStatus::InProgress;
+            }
+            SolverStatus::InProgress => {
+                match self.cul_de_sacs.pop() {
+                    Some(cell) => {
+                        maze.change_cell_state(&cell, CellState::Filled);
+                        self.cells_expanded += 1;
+                    }
+                    None => {
+                        match unfilled_route(maze, &self.start, &self.finish) {
+                            Some(route) => {
+                                self.solution = route;
+                                self.status = if self.solution.is_empty() { SolverStatus::Done } else { SolverStatus::Solved };
+                            }
+                            None => {
+                                self.status = SolverStatus::Failed;
+                            }
+                        }
+                    }
+                }
+            }
+            SolverStatus::Solved => {
+                if let Some(cell) = self.solution.pop() {
+                    maze.change_cell_state(&cell, CellState::Solution);
+                }
+                if self.solution.is_empty() {
+                    self.status = SolverStatus::Done;
+                }
+            }
+            SolverStatus::Done | SolverStatus::Failed => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &SolverStatus {
+        &self.status
+    }
+
+    fn reset(&self, maze: &mut Maze) {
+        reset_solver(maze);
+    }
+
+    fn cells_expanded(&self) -> usize {
+        self.cells_expanded
+    }
+}
+
+impl CulDeSacFilling {
+    pub fn new(start: &Coord, finish: &Coord) -> Self {
+        CulDeSacFilling {
+            start: *start,
+            finish: *finish,
+            cul_de_sacs: Vec::new(),
+            solution: Vec::new(),
+            cells_expanded: 0,
+            status: SolverStatus::Initialized,
+        }
+    }
+
+    fn index(maze: &Maze, coord: &Coord) -> usize {
+        maze.cell_index(coord)
+    }
+
+    fn coord(maze: &Maze, index: usize) -> Coord {
+        *maze.grid()[index].coord()
+    }
+
+    // Every cell that hangs off the rest of the maze behind a single passage
+    // (a bridge) is a cul-de-sac unless the start or finish lies behind it.
+    // Returned nearest-first so popping fills from the far ends inward.
+    fn find_cul_de_sacs(&self, maze: &Maze) -> Vec<Coord> {
+        let cell_count = maze.cell_count();
+        let neighbors: Vec<Vec<usize>> = (0..cell_count)
+            .map(|index| open_neighbors(maze, &Self::coord(maze, index)).iter().map(|neighbor| Self::index(maze, neighbor)).collect())
+            .collect();
+        let bridges = Self::find_bridges(&neighbors);
+
+        let mut component: Vec<usize> = vec![usize::MAX; cell_count];
+        let mut component_count = 0;
+        for root in 0..cell_count {
+            if component[root] != usize::MAX {
+                continue;
+            }
+            component[root] = component_count;
+            let mut stack: Vec<usize> = vec![root];
+            while let Some(index) = stack.pop() {
+                for &neighbor in &neighbors[index] {
+                    if component[neighbor] == usize::MAX && !bridges.contains(&(index.min(neighbor), index.max(neighbor))) {
+                        component[neighbor] = component_count;
+                        stack.push(neighbor);
+                    }
+                }
+            }
+            component_count += 1;
+        }
+
+        let mut bridge_tree: Vec<Vec<usize>> = vec![Vec::new(); component_count];
+        for (a, b) in &bridges {
+            bridge_tree[component[*a]].push(component[*b]);
+            bridge_tree[component[*b]].push(component[*a]);
+        }
+
+        let start_component = component[Self::index(maze, &self.start)];
+        let finish_component = component[Self::index(maze, &self.finish)];
+        let mut parent: Vec<usize> = vec![usize::MAX; component_count];
+        parent[start_component] = start_component;
+        let mut frontier: VecDeque<usize> = VecDeque::from([start_component]);
+        while let Some(current) = frontier.pop_front() {
+            for &next in &bridge_tree[current] {
+                if parent[next] == usize::MAX {
+                    parent[next] = current;
+                    frontier.push_back(next);
+                }
+            }
+        }
+
+        let mut kept: HashSet<usize> = HashSet::new();
+        if parent[finish_component] != usize::MAX {
+            let mut current = finish_component;
+            kept.insert(current);
+            while current != start_component {
+                current = parent[current];
+                kept.insert(current);
+            }
+        }
+
+        let mut distance: Vec<usize> = vec![usize::MAX; cell_count];
+        let mut frontier: VecDeque<usize> = VecDeque::new();
+        for index in 0..cell_count {
+            if kept.contains(&component[index]) {
+                distance[index] = 0;
+                frontier.push_back(index);
+            }
+        }
+        while let Some(index) = frontier.pop_front() {
+            for &neighbor in &neighbors[index] {
+                if distance[neighbor] == usize::MAX {
+                    distance[neighbor] = distance[index] + 1;
+                    frontier.push_back(neighbor);
+                }
+            }
+        }
+
+        let mut cul_de_sacs: Vec<usize> = (0..cell_count)
+            .filter(|index| distance[*index] > 0)
+            .filter(|index| *index != Self::index(maze, &self.start) && *index != Self::index(maze, &self.finish))
+            .collect();
+        cul_de_sacs.sort_by_key(|index| distance[*index]);
+
+        cul_de_sacs.into_iter().map(|index| Self::coord(maze, index)).collect()
+    }
+
+    fn find_bridges(neighbors: &[Vec<usize>]) -> HashSet<(usize, usize)> {
+        let mut bridges: HashSet<(usize, usize)> = HashSet::new();
+        let mut discovered: Vec<usize> = vec![usize::MAX; neighbors.len()];
+        let mut low: Vec<usize> = vec![0; neighbors.len()];
+        let mut time = 0;
+
+        for root in 0..neighbors.len() {
+            if discovered[root] != usize::MAX {
+                continue;
+            }
+            discovered[root] = time;
+            low[root] = time;
+            time += 1;
+            let mut stack: Vec<(usize, usize, usize)> = vec![(root, usize::MAX, 0)];
+
+            while let Some((index, parent, next)) = stack.pop() {
+                if next < neighbors[index].len() {
+                    stack.push((index, parent, next + 1));
+                    let neighbor = neighbors[index][next];
+                    if neighbor == parent {
+                        continue;
+                    }
+                    if discovered[neighbor] == usize::MAX {
+                        discovered[neighbor] = time;
+                        low[neighbor] = time;
+                        time += 1;
+                        stack.push((neighbor, index, 0));
+                    }
+                    else {
+                        low[index] = low[index].min(discovered[neighbor]);
+                    }
+                }
+                else if parent != usize::MAX {
+                    low[parent] = low[parent].min(low[index]);
+                    if low[index] > discovered[parent] {
+                        bridges.insert((index.min(parent), index.max(parent)));
+                    }
+                }
+            }
+        }
+
+        bridges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver_algorithms::solver_helpers::tests::{is_single_route, solve};
+
+    // A corridor along the top row, with a spur hanging below each end and
+    // a ring of six cells that is only reachable through the middle cell.
+    fn maze_with_looped_cul_de_sac() -> Maze {
+        let mut maze = Maze::new(3, 5);
+        let passages = [
+            ((0, 0), (0, 1)), ((0, 1), (0, 2)), ((0, 2), (0, 3)), ((0, 3), (0, 4)),
+            ((0, 0), (1, 0)), ((1, 0), (2, 0)), ((0, 4), (1, 4)), ((1, 4), (2, 4)),
+            ((0, 2), (1, 2)),
+            ((1, 1), (1, 2)), ((1, 2), (1, 3)), ((1, 3), (2, 3)), ((2, 3), (2, 2)), ((2, 2), (2, 1)), ((2, 1), (1, 1)),
+        ];
+        for ((from_y, from_x), (to_y, to_x)) in passages {
+            maze.remove_wall_between(&Coord{ y: from_y, x: from_x }, &Coord{ y: to_y, x: to_x });
+        }
+        maze
+    }
+
+    #[test]
+    fn fills_looped_cul_de_sacs_down_to_one_route() {
+        let mut maze = maze_with_looped_cul_de_sac();
+        let (start, finish) = (Coord{ y: 0, x: 0 }, Coord{ y: 0, x: 4 });
+        let solver = solve(&mut maze, "cul_de_sac_filling", &start, &finish);
+
+        assert!(solver.status() == &SolverStatus::Done);
+        assert!(is_single_route(&maze, &start, &finish));
+        for cell in maze.grid().iter().filter(|cell| cell.coord().y > 0) {
+            assert!(cell.state() == CellState::Filled, "{:?} was left open", cell.coord());
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,116 @@
+use std::collections::VecDeque;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::{CellState, Coord};
+use crate::solver_algorithms::solver_helpers::{open_neighbors, reset_solver, unfilled_route, SolverAlgo, SolverStatus};
+
+pub struct DeadEndFilling {
+    start: Coord,
+    finish: Coord,
+    dead_ends: VecDeque<Coord>,
+    solution: Vec<Coord>,
+    cells_expanded: usize,
+    status: SolverStatus,
+}
+
+impl SolverAlgo for DeadEndFilling {
+    fn find_solution(&mut self, maze: &mut Maze) {
+        match self.status {
+            SolverStatus::Initialized => {
+                maze.change_cell_state(&self.start, CellState::Start);
+                maze.change_cell_state(&self.finish, CellState::Finish);
+                if self.start == self.finish {
+                    self.status = SolverStatus::Done;
+                    return;
+                }
+                for y in 0..maze.height() {
+                    for x in 0..maze.row_width(y) {
+                        let coord = Coord{ y, x };
+                        if self.is_dead_end(maze, &coord) {
+                            self.dead_ends.push_back(coord);
+                        }
+                    }
+                }
+                self.status = SolverStatus::InProgress;
+            }
+            SolverStatus::InProgress => {
+                let dead_end = match self.dead_ends.pop_front() {
+                    Some(dead_end) => dead_end,
+                    None => {
+                        self.finish_filling(maze);
+                        return;
+                    }
+                };
+                if maze.get_cell_ref(&dead_end).state() == CellState::Filled {
+                    return;
+                }
+
+                maze.change_cell_state(&dead_end, CellState::Filled);
+                self.cells_expanded += 1;
+                for neighbor in open_neighbors(maze, &dead_end) {
+                    if self.is_dead_end(maze, &neighbor) {
+                        self.dead_ends.push_back(neighbor);
+                    }
+                }
+            }
+            SolverStatus::Solved => {
+                if let Some(cell) = self.solution.pop() {
+                    maze.change_cell_state(&cell, CellState::Solution);
+                }
+                if self.solution.is_empty() {
+                    self.status = SolverStatus::Done;
+                }
+            }
+            SolverStatus::Done | SolverStatus::Failed => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &SolverStatus {
+        &self.status
+    }
+
+    fn reset(&self, maze: &mut Maze) {
+        reset_solver(maze);
+    }
+
+    fn cells_expanded(&self) -> usize {
+        self.cells_expanded
+    }
+}
+
+impl DeadEndFilling {
+    pub fn new(start: &Coord, finish: &Coord) -> Self {
+        DeadEndFilling {
+            start: *start,
+            finish: *finish,
+            dead_ends: VecDeque::new(),
+            solution: Vec::new(),
+            cells_expanded: 0,
+            status: SolverStatus::Initialized,
+        }
+    }
+
+    fn is_dead_end(&self, maze: &Maze, coord: &Coord) -> bool {
+        if *coord == self.start || *coord == self.finish || maze.get_cell_ref(coord).state() == CellState::Filled {
+            return false;
+        }
+
+        open_neighbors(maze, coord).iter()
+            .filter(|neighbor| maze.get_cell_ref(neighbor).state() != CellState::Filled)
+            .count() <= 1
+    }
+
+    fn finish_filling(&mut self, maze: &Maze) {
+        match unfilled_route(maze, &self.start, &self.finish) {
+            Some(route) => {
+                self.solution = route;
+                self.status = if self.solution.is_empty() { SolverStatus::Done } else { SolverStatus::Solved };
+            }
+            None => {
+                self.status = SolverStatus::Failed;
+            }
+        }
+    }
+}
\ No newline at end of file
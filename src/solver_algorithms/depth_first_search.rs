@@ -1,9 +1,9 @@
 use std::collections::{HashMap};
+use std::collections::hash_map::Entry;
 
-use dioxus::prelude::*;
 use crate::structures::maze::Maze;
 use crate::structures::cell::{CellState, Coord};
-use crate::solver_algorithms::solver_helpers::{reset_solver, solved, SolverAlgo, SolverStatus};
+use crate::solver_algorithms::solver_helpers::{open_neighbors, reset_solver, solved, SolverAlgo, SolverStatus};
 
 pub struct DepthFirstSearch {
     start: Coord,
@@ -11,13 +11,12 @@ pub struct DepthFirstSearch {
     frontier: Vec<Coord>,
     explored: HashMap<Coord, Coord>,
     current_cell: Coord,
+    cells_expanded: usize,
     status: SolverStatus,
 }
 
 impl SolverAlgo for DepthFirstSearch {
-    fn find_solution(&mut self, maze: &mut Signal<Maze>) {
-        let maze: &mut Maze = &mut maze.write();
-
+    fn find_solution(&mut self, maze: &mut Maze) {
         match self.status {
             SolverStatus::Initialized => {
                 maze.change_cell_state(&self.start, CellState::Start);
@@ -26,7 +25,14 @@ impl SolverAlgo for DepthFirstSearch {
                 self.status = SolverStatus::InProgress;
             }
             SolverStatus::InProgress => {
-                self.current_cell = self.frontier.pop().unwrap();
+                self.current_cell = match self.frontier.pop() {
+                    Some(cell) => cell,
+                    None => {
+                        self.status = SolverStatus::Failed;
+                        return;
+                    }
+                };
+                self.cells_expanded += 1;
                 self.add_adjacent_cells_to_frontier(maze, &self.current_cell.clone());
                 if solved(&self.explored, &self.finish) {
                     self.current_cell = *self.explored.get(&self.finish).unwrap();
@@ -40,7 +46,7 @@ impl SolverAlgo for DepthFirstSearch {
                     self.status = SolverStatus::Done;
                 }
             }
-            SolverStatus::Done => {
+            SolverStatus::Done | SolverStatus::Failed => {
                 panic!("You shouldn't be here");
             }
         }
@@ -50,10 +56,13 @@ impl SolverAlgo for DepthFirstSearch {
         &self.status
     }
 
-    fn reset(&self, maze: &mut Signal<Maze>) {
-        let maze = &mut maze.write();
+    fn reset(&self, maze: &mut Maze) {
         reset_solver(maze);
     }
+
+    fn cells_expanded(&self) -> usize {
+        self.cells_expanded
+    }
 }
 
 impl DepthFirstSearch {
@@ -64,43 +73,19 @@ impl DepthFirstSearch {
             frontier: Vec::new(),
             explored: HashMap::new(),
             current_cell: *start,
+            cells_expanded: 0,
             status: SolverStatus::Initialized,
         }
     }
 
     fn add_adjacent_cells_to_frontier(&mut self, maze: &mut Maze, current_cell: &Coord) {
-        let cell = *maze.get_cell_ref(current_cell);
-
-        if !cell.walls()[0] && !self.explored.contains_key(&Coord{ y: cell.coord().y - 1, x: cell.coord().x }) {
-            let new_frontier_cell = Coord{ y: cell.coord().y - 1, x: cell.coord().x };
-            self.explored.insert(new_frontier_cell, *current_cell);
-            self.frontier.push(new_frontier_cell);
-            if new_frontier_cell != self.start && new_frontier_cell != self.finish {
-                maze.change_cell_state(&new_frontier_cell, CellState::Frontier);
-            }
-        }
-        if !cell.walls()[1] && !self.explored.contains_key(&Coord{ y: cell.coord().y, x: cell.coord().x + 1 }) {
-            let new_frontier_cell = Coord{ y: cell.coord().y, x: cell.coord().x + 1 };
-            self.explored.insert(new_frontier_cell, *current_cell);
-            self.frontier.push(new_frontier_cell);
-            if new_frontier_cell != self.start && new_frontier_cell != self.finish {
-                maze.change_cell_state(&new_frontier_cell, CellState::Frontier);
-            }
-        }
-        if !cell.walls()[2] && !self.explored.contains_key(&Coord{ y: cell.coord().y + 1, x: cell.coord().x }) {
-            let new_frontier_cell = Coord{ y: cell.coord().y + 1, x: cell.coord().x };
-            self.explored.insert(new_frontier_cell, *current_cell);
-            self.frontier.push(new_frontier_cell);
-            if new_frontier_cell != self.start && new_frontier_cell != self.finish {
-                maze.change_cell_state(&new_frontier_cell, CellState::Frontier);
-            }
-        }
-        if !cell.walls()[3] && !self.explored.contains_key(&Coord{ y: cell.coord().y, x: cell.coord().x - 1 }) {
-            let new_frontier_cell = Coord{ y: cell.coord().y, x: cell.coord().x - 1 };
-            self.explored.insert(new_frontier_cell, *current_cell);
-            self.frontier.push(new_frontier_cell);
-            if new_frontier_cell != self.start && new_frontier_cell != self.finish {
-                maze.change_cell_state(&new_frontier_cell, CellState::Frontier);
+        for new_frontier_cell in open_neighbors(maze, current_cell) {
+            if let Entry::Vacant(entry) = self.explored.entry(new_frontier_cell) {
+                entry.insert(*current_cell);
+                self.frontier.push(new_frontier_cell);
+                if new_frontier_cell != self.start && new_frontier_cell != self.finish {
+                    maze.change_cell_state(&new_frontier_cell, CellState::Frontier);
+                }
             }
         }
     }
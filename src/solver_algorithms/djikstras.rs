@@ -1,11 +1,9 @@
-use std::collections::{HashMap, BinaryHeap};
+use std::collections::{HashMap, HashSet, BinaryHeap};
 use std::cmp::Ordering;
 
-use dioxus::prelude::*;
-
 use crate::structures::maze::Maze;
 use crate::structures::cell::{CellState, Coord};
-use crate::solver_algorithms::solver_helpers::{reset_solver, solved, SolverAlgo, SolverStatus};
+use crate::solver_algorithms::solver_helpers::{open_neighbors, reset_solver, step_cost, SolverAlgo, SolverStatus};
 
 #[derive(Copy, Clone, Eq, PartialEq)]
 struct DistanceToStart {
@@ -29,19 +27,21 @@ pub struct Djikstras {
     start: Coord,
     finish: Coord,
     explored: HashMap<Coord, Coord>,
+    distances: HashMap<Coord, usize>,
+    closed: HashSet<Coord>,
     frontier: BinaryHeap<DistanceToStart>,
     current_cell: Coord,
+    cells_expanded: usize,
     status: SolverStatus,
 }
 
 impl SolverAlgo for Djikstras {
-    fn find_solution(&mut self, maze: &mut Signal<Maze>) {
-        let maze: &mut Maze = &mut maze.write();
-
+    fn find_solution(&mut self, maze: &mut Maze) {
         match self.status {
             SolverStatus::Initialized => {
                 maze.change_cell_state(&self.start, CellState::Start);
                 maze.change_cell_state(&self.finish, CellState::Finish);
+                self.distances.insert(self.start, 0);
                 self.frontier.push(DistanceToStart {
                     cell_coord: self.start,
                     distance: 0,
@@ -49,25 +49,43 @@ impl SolverAlgo for Djikstras {
                 self.status = SolverStatus::InProgress;
             }
             SolverStatus::InProgress => {
-                println!("Frontier has {} coords", self.frontier.len());
-                let temp: DistanceToStart = self.frontier.pop().unwrap();
+                let temp: DistanceToStart = match self.frontier.pop() {
+                    Some(temp) => temp,
+                    None => {
+                        self.status = SolverStatus::Failed;
+                        return;
+                    }
+                };
+                if !self.closed.insert(temp.cell_coord) {
+                    return;
+                }
                 self.current_cell = temp.cell_coord;
-                let distance: usize = temp.distance;
-                self.add_adjacent_cells_to_frontier(maze, distance);
-                if solved(&self.explored, &self.finish) {
+                self.cells_expanded += 1;
+
+                if self.current_cell == self.finish {
+                    if self.finish == self.start {
+                        self.status = SolverStatus::Done;
+                        return;
+                    }
                     self.current_cell = *self.explored.get(&self.finish).unwrap();
                     self.status = SolverStatus::Solved;
                 }
+                else {
+                    self.add_adjacent_cells_to_frontier(maze, temp.distance);
+                }
             }
             SolverStatus::Solved => {
+                if self.current_cell == self.start {
+                    self.status = SolverStatus::Done;
+                    return;
+                }
                 maze.change_cell_state(&self.current_cell, CellState::Solution);
                 self.current_cell = *self.explored.get(&self.current_cell).unwrap();
                 if self.current_cell == self.start {
                     self.status = SolverStatus::Done;
-                    println!("Solved?");
                 }
             }
-            SolverStatus::Done => {
+            SolverStatus::Done | SolverStatus::Failed => {
                 panic!("You shouldn't be here");
             }
         }
@@ -77,10 +95,13 @@ impl SolverAlgo for Djikstras {
         &self.status
     }
 
-    fn reset(&self, maze: &mut Signal<Maze>) {
-        let maze = &mut maze.write();
+    fn reset(&self, maze: &mut Maze) {
         reset_solver(maze);
     }
+
+    fn cells_expanded(&self) -> usize {
+        self.cells_expanded
+    }
 }
 impl Djikstras {
     pub fn new(start: &Coord, finish: &Coord) -> Self {
@@ -88,46 +109,37 @@ impl Djikstras {
             start: *start,
             finish: *finish,
             explored: HashMap::new(),
+            distances: HashMap::new(),
+            closed: HashSet::new(),
             frontier: BinaryHeap::new(),
             current_cell: *start,
+            cells_expanded: 0,
             status: SolverStatus::Initialized,
         }
     }
 
     fn add_adjacent_cells_to_frontier(&mut self, maze: &mut Maze, distance: usize) {
-        let cell = *maze.get_cell_ref(&self.current_cell);
-
-        if !cell.walls()[0] && !self.explored.contains_key(&Coord{ y: cell.coord().y - 1, x: cell.coord().x }) {
-            let new_frontier_cell = Coord{ y: cell.coord().y - 1, x: cell.coord().x };
-            self.explored.insert(new_frontier_cell, self.current_cell);
-            self.frontier.push(DistanceToStart{ cell_coord: new_frontier_cell, distance: distance + 1 });
-            if new_frontier_cell != self.start && new_frontier_cell != self.finish {
-                maze.change_cell_state(&new_frontier_cell, CellState::Frontier);
-            }
+        for new_frontier_cell in open_neighbors(maze, &self.current_cell) {
+            let new_distance = distance + step_cost(maze, &self.current_cell, &new_frontier_cell);
+            self.process_frontier_cell(maze, new_frontier_cell, new_distance);
         }
-        if !cell.walls()[1] && !self.explored.contains_key(&Coord{ y: cell.coord().y, x: cell.coord().x + 1 }) {
-            let new_frontier_cell = Coord{ y: cell.coord().y, x: cell.coord().x + 1 };
-            self.explored.insert(new_frontier_cell, self.current_cell);
-            self.frontier.push(DistanceToStart{ cell_coord: new_frontier_cell, distance: distance + 1 });
-            if new_frontier_cell != self.start && new_frontier_cell != self.finish {
-                maze.change_cell_state(&new_frontier_cell, CellState::Frontier);
-            }
+    }
+
+    fn process_frontier_cell(&mut self, maze: &mut Maze, new_frontier_cell: Coord, distance: usize) {
+        if self.closed.contains(&new_frontier_cell) {
+            return;
         }
-        if !cell.walls()[2] && !self.explored.contains_key(&Coord{ y: cell.coord().y + 1, x: cell.coord().x }) {
-            let new_frontier_cell = Coord{ y: cell.coord().y + 1, x: cell.coord().x };
-            self.explored.insert(new_frontier_cell, self.current_cell);
-            self.frontier.push(DistanceToStart{ cell_coord: new_frontier_cell, distance: distance + 1 });
-            if new_frontier_cell != self.start && new_frontier_cell != self.finish {
-                maze.change_cell_state(&new_frontier_cell, CellState::Frontier);
+        if let Some(known_distance) = self.distances.get(&new_frontier_cell) {
+            if *known_distance <= distance {
+                return;
             }
         }
-        if !cell.walls()[3] && !self.explored.contains_key(&Coord{ y: cell.coord().y, x: cell.coord().x - 1 }) {
-            let new_frontier_cell = Coord{ y: cell.coord().y, x: cell.coord().x - 1 };
-            self.explored.insert(new_frontier_cell, self.current_cell);
-            self.frontier.push(DistanceToStart{ cell_coord: new_frontier_cell, distance: distance + 1 });
-            if new_frontier_cell != self.start && new_frontier_cell != self.finish {
-                maze.change_cell_state(&new_frontier_cell, CellState::Frontier);
-            }
+
+        self.distances.insert(new_frontier_cell, distance);
+        self.explored.insert(new_frontier_cell, self.current_cell);
+        self.frontier.push(DistanceToStart{ cell_coord: new_frontier_cell, distance });
+        if new_frontier_cell != self.start && new_frontier_cell != self.finish {
+            maze.change_cell_state(&new_frontier_cell, CellState::Frontier);
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,56 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::Coord;
+use crate::solver_algorithms::solver_helpers::{open_neighbors, reset_solver};
+
+pub fn distances_from(maze: &Maze, origin: &Coord) -> HashMap<Coord, usize> {
+    let mut distances: HashMap<Coord, usize> = HashMap::from([(*origin, 0)]);
+    let mut frontier: VecDeque<Coord> = VecDeque::from([*origin]);
+
+    while let Some(cell) = frontier.pop_front() {
+        let distance = distances[&cell];
+        for neighbor in open_neighbors(maze, &cell) {
+            distances.entry(neighbor).or_insert_with(|| {
+                frontier.push_back(neighbor);
+                distance + 1
+            });
+        }
+    }
+
+    distances
+}
+
+pub fn maze_diameter(maze: &Maze) -> (Coord, Coord) {
+    let first_end = farthest_cell(maze, &Coord{ y: 0, x: 0 });
+    let second_end = farthest_cell(maze, &first_end);
+    (first_end, second_end)
+}
+
+fn farthest_cell(maze: &Maze, origin: &Coord) -> Coord {
+    distances_from(maze, origin).into_iter()
+        .max_by_key(|(cell, distance)| (*distance, std::cmp::Reverse((cell.y, cell.x))))
+        .map(|(cell, _)| cell)
+        .unwrap_or(*origin)
+}
+
+pub fn heatmap_color(distance: usize, max_distance: usize) -> String {
+    let hue = match max_distance {
+        0 => 240,
+        _ => 240 - 240 * distance / max_distance,
+    };
+    format!("hsl({hue}, 75%, 60%)")
+}
+
+pub fn paint_distance_heatmap(maze: &mut Maze, origin: &Coord, show_distances: bool) {
+    reset_solver(maze);
+
+    let distances = distances_from(maze, origin);
+    let max_distance = distances.values().copied().max().unwrap_or(0);
+    for (cell, distance) in distances {
+        maze.paint_cell(&cell, &heatmap_color(distance, max_distance));
+        if show_distances {
+            maze.set_cell_label(&cell, distance.to_string());
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,157 @@
+use std::collections::{HashMap, VecDeque};
+use std::collections::hash_map::Entry;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::{CellState, Coord};
+use crate::structures::topology::MAX_DIRECTIONS;
+use crate::solver_algorithms::solver_helpers::{move_walker, open_neighbors, reset_solver, AgentRoute, SolverAlgo, SolverStatus};
+
+pub struct FogOfWar {
+    start: Coord,
+    finish: Coord,
+    vision: usize,
+    current_cell: Coord,
+    known_walls: HashMap<Coord, [bool; MAX_DIRECTIONS]>,
+    route: AgentRoute,
+    solution: Vec<Coord>,
+    cells_expanded: usize,
+    status: SolverStatus,
+}
+
+impl SolverAlgo for FogOfWar {
+    fn find_solution(&mut self, maze: &mut Maze) {
+        match self.status {
+            SolverStatus::Initialized => {
+                maze.change_cell_state(&self.start, CellState::Start);
+                maze.change_cell_state(&self.finish, CellState::Finish);
+                self.look_around(maze);
+                self.status = if self.start == self.finish { SolverStatus::Done } else { SolverStatus::InProgress };
+            }
+            SolverStatus::InProgress => {
+                let next_cell = match self.plan_next_step(maze) {
+                    Some(next_cell) => next_cell,
+                    None => {
+                        self.status = SolverStatus::Failed;
+                        return;
+                    }
+                };
+
+                move_walker(maze, &self.current_cell, &next_cell);
+                self.route.step_to(&next_cell);
+                self.current_cell = next_cell;
+                self.cells_expanded += 1;
+                self.look_around(maze);
+
+                if self.current_cell == self.finish {
+                    let route = std::mem::replace(&mut self.route, AgentRoute::new(&self.start)).into_cells();
+                    self.solution = route[1..route.len() - 1].to_vec();
+                    self.status = if self.solution.is_empty() { SolverStatus::Done } else { SolverStatus::Solved };
+                }
+            }
+            SolverStatus::Solved => {
+                if let Some(cell) = self.solution.pop() {
+                    maze.change_cell_state(&cell, CellState::Solution);
+                }
+                if self.solution.is_empty() {
+                    self.status = SolverStatus::Done;
+                }
+            }
+            SolverStatus::Done | SolverStatus::Failed => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &SolverStatus {
+        &self.status
+    }
+
+    fn reset(&self, maze: &mut Maze) {
+        reset_solver(maze);
+    }
+
+    fn cells_expanded(&self) -> usize {
+        self.cells_expanded
+    }
+}
+
+impl FogOfWar {
+    pub fn new(start: &Coord, finish: &Coord, vision: usize) -> Self {
+        FogOfWar {
+            start: *start,
+            finish: *finish,
+            vision,
+            current_cell: *start,
+            known_walls: HashMap::new(),
+            route: AgentRoute::new(start),
+            solution: Vec::new(),
+            cells_expanded: 0,
+            status: SolverStatus::Initialized,
+        }
+    }
+
+    fn look_around(&mut self, maze: &mut Maze) {
+        let mut distances: HashMap<Coord, usize> = HashMap::from([(self.current_cell, 0)]);
+        let mut frontier: VecDeque<Coord> = VecDeque::from([self.current_cell]);
+
+        while let Some(cell) = frontier.pop_front() {
+            if let Entry::Vacant(entry) = self.known_walls.entry(cell) {
+                entry.insert(*maze.get_cell_ref(&cell).walls());
+                if maze.get_cell_ref(&cell).state() == CellState::Path {
+                    maze.change_cell_state(&cell, CellState::Seen);
+                }
+            }
+            let distance = distances[&cell];
+            if distance == self.vision {
+                continue;
+            }
+            for neighbor in open_neighbors(maze, &cell) {
+                if let Entry::Vacant(entry) = distances.entry(neighbor) {
+                    entry.insert(distance + 1);
+                    frontier.push_back(neighbor);
+                }
+            }
+        }
+    }
+
+    // A seen cell shows where each of its exits leads. Unseen cells are assumed open,
+    // unless a seen neighbor is walled on that side or carries a tunnel to pass under.
+    fn believed_passage(&self, maze: &Maze, cell: &Coord, direction: usize) -> Option<Coord> {
+        if self.known_walls.contains_key(cell) {
+            return maze.passage(cell, direction);
+        }
+        let neighbor = maze.neighbor(cell, direction)?;
+        match (self.known_walls.get(&neighbor), maze.direction_to(&neighbor, cell)) {
+            (Some(_), _) if maze.tunnels_under(&neighbor, direction) => maze.neighbor(&neighbor, direction),
+            (Some(walls), Some(opposite)) if walls[opposite] => None,
+            _ => Some(neighbor),
+        }
+    }
+
+    fn plan_next_step(&self, maze: &Maze) -> Option<Coord> {
+        let mut explored: HashMap<Coord, Coord> = HashMap::from([(self.current_cell, self.current_cell)]);
+        let mut frontier: VecDeque<Coord> = VecDeque::from([self.current_cell]);
+
+        while let Some(cell) = frontier.pop_front() {
+            if cell == self.finish {
+                break;
+            }
+            for direction in 0..maze.direction_count(&cell) {
+                let Some(neighbor) = self.believed_passage(maze, &cell, direction) else { continue };
+                if let Entry::Vacant(entry) = explored.entry(neighbor) {
+                    entry.insert(cell);
+                    frontier.push_back(neighbor);
+                }
+            }
+        }
+
+        let mut cell = self.finish;
+        let mut previous_cell = *explored.get(&cell)?;
+        while previous_cell != self.current_cell {
+            cell = previous_cell;
+            previous_cell = explored[&cell];
+        }
+
+        Some(cell)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,129 @@
+use std::collections::{HashMap, BinaryHeap};
+use std::cmp::Ordering;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::{CellState, Coord};
+use crate::solver_algorithms::solver_helpers::{open_neighbors, reset_solver, solved, Heuristic, SolverAlgo, SolverStatus};
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct DistanceToFinish {
+    cell_coord: Coord,
+    estimate: usize,
+}
+
+impl Ord for DistanceToFinish {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl PartialOrd for DistanceToFinish {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub struct GreedyBestFirst {
+    start: Coord,
+    finish: Coord,
+    heuristic: Heuristic,
+    explored: HashMap<Coord, Coord>,
+    frontier: BinaryHeap<DistanceToFinish>,
+    current_cell: Coord,
+    cells_expanded: usize,
+    status: SolverStatus,
+}
+
+impl SolverAlgo for GreedyBestFirst {
+    fn find_solution(&mut self, maze: &mut Maze) {
+        match self.status {
+            SolverStatus::Initialized => {
+                maze.change_cell_state(&self.start, CellState::Start);
+                maze.change_cell_state(&self.finish, CellState::Finish);
+                self.explored.insert(self.start, self.start);
+                self.frontier.push(DistanceToFinish {
+                    cell_coord: self.start,
+                    estimate: self.heuristic.estimate(maze, &self.start, &self.finish),
+                });
+                self.status = SolverStatus::InProgress;
+            }
+            SolverStatus::InProgress => {
+                self.current_cell = match self.frontier.pop() {
+                    Some(next) => next.cell_coord,
+                    None => {
+                        self.status = SolverStatus::Failed;
+                        return;
+                    }
+                };
+                self.cells_expanded += 1;
+                self.add_adjacent_cells_to_frontier(maze);
+                if solved(&self.explored, &self.finish) {
+                    self.current_cell = *self.explored.get(&self.finish).unwrap();
+                    self.status = SolverStatus::Solved;
+                }
+            }
+            SolverStatus::Solved => {
+                if self.current_cell == self.start {
+                    self.status = SolverStatus::Done;
+                    return;
+                }
+                maze.change_cell_state(&self.current_cell, CellState::Solution);
+                self.current_cell = *self.explored.get(&self.current_cell).unwrap();
+                if self.current_cell == self.start {
+                    self.status = SolverStatus::Done;
+                }
+            }
+            SolverStatus::Done | SolverStatus::Failed => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &SolverStatus {
+        &self.status
+    }
+
+    fn reset(&self, maze: &mut Maze) {
+        reset_solver(maze);
+    }
+
+    fn cells_expanded(&self) -> usize {
+        self.cells_expanded
+    }
+}
+
+impl GreedyBestFirst {
+    pub fn new(start: &Coord, finish: &Coord, heuristic: Heuristic) -> Self {
+        GreedyBestFirst {
+            start: *start,
+            finish: *finish,
+            heuristic,
+            explored: HashMap::new(),
+            frontier: BinaryHeap::new(),
+            current_cell: *start,
+            cells_expanded: 0,
+            status: SolverStatus::Initialized,
+        }
+    }
+
+    fn add_adjacent_cells_to_frontier(&mut self, maze: &mut Maze) {
+        for new_frontier_cell in open_neighbors(maze, &self.current_cell) {
+            self.process_frontier_cell(maze, new_frontier_cell);
+        }
+    }
+
+    fn process_frontier_cell(&mut self, maze: &mut Maze, new_frontier_cell: Coord) {
+        if self.explored.contains_key(&new_frontier_cell) {
+            return;
+        }
+
+        self.explored.insert(new_frontier_cell, self.current_cell);
+        self.frontier.push(DistanceToFinish {
+            cell_coord: new_frontier_cell,
+            estimate: self.heuristic.estimate(maze, &new_frontier_cell, &self.finish),
+        });
+        if new_frontier_cell != self.start && new_frontier_cell != self.finish {
+            maze.change_cell_state(&new_frontier_cell, CellState::Frontier);
+        }
+    }
+}
\ No newline at end of file
@@ -1,4 +1,16 @@
 pub mod breadth_first_search;
 pub mod solver_helpers;
 pub mod djikstras;
-pub mod depth_first_search;
\ No newline at end of file
+pub mod depth_first_search;
+pub mod a_star;
+pub mod greedy_best_first;
+pub mod bidirectional_bfs;
+pub mod wall_follower;
+pub mod pledge;
+pub mod tremaux;
+pub mod dead_end_filling;
+pub mod cul_de_sac_filling;
+pub mod random_mouse;
+pub mod fog_of_war;
+pub mod flood_fill;
+pub mod waypoint_tour;
\ No newline at end of file
@@ -0,0 +1,133 @@
+use std::collections::HashSet;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::{CellState, Coord};
+use crate::solver_algorithms::solver_helpers::{move_walker, reset_solver, AgentRoute, SolverAlgo, SolverStatus};
+
+pub struct Pledge {
+    start: Coord,
+    finish: Coord,
+    left_hand: bool,
+    current_cell: Coord,
+    main_direction: usize,
+    heading: usize,
+    turn_sum: i32,
+    following: bool,
+    route: AgentRoute,
+    seen_states: HashSet<(Coord, usize, i32, bool)>,
+    solution: Vec<Coord>,
+    cells_expanded: usize,
+    status: SolverStatus,
+}
+
+impl SolverAlgo for Pledge {
+    fn find_solution(&mut self, maze: &mut Maze) {
+        match self.status {
+            SolverStatus::Initialized => {
+                maze.change_cell_state(&self.start, CellState::Start);
+                maze.change_cell_state(&self.finish, CellState::Finish);
+                self.status = if self.start == self.finish { SolverStatus::Done } else { SolverStatus::InProgress };
+            }
+            SolverStatus::InProgress => {
+                let walls = *maze.get_cell_ref(&self.current_cell).walls();
+
+                if !self.following && !walls[self.main_direction] {
+                    self.heading = self.main_direction;
+                }
+                else {
+                    let turns: &[(usize, i32)] = match (self.following, self.left_hand) {
+                        (false, true) => &[(1, 1), (2, 2), (3, 3)],
+                        (false, false) => &[(3, -1), (2, -2), (1, -3)],
+                        (true, true) => &[(3, -1), (0, 0), (1, 1), (2, 2)],
+                        (true, false) => &[(1, 1), (0, 0), (3, -1), (2, -2)],
+                    };
+                    let turn = turns.iter().find(|(turn, _)| !walls[(self.heading + turn) % 4]);
+                    match turn {
+                        Some((turn, delta)) => {
+                            self.heading = (self.heading + turn) % 4;
+                            self.turn_sum += delta;
+                            self.following = true;
+                        }
+                        None => {
+                            self.status = SolverStatus::Failed;
+                            return;
+                        }
+                    }
+                }
+
+                let next_cell = maze.passage(&self.current_cell, self.heading).unwrap();
+                move_walker(maze, &self.current_cell, &next_cell);
+                self.route.step_to(&next_cell);
+                self.current_cell = next_cell;
+                self.cells_expanded += 1;
+                if self.following && self.turn_sum == 0 {
+                    self.following = false;
+                }
+
+                let move_limit = 16 * maze.width() * maze.height();
+                if self.current_cell == self.finish {
+                    let route = std::mem::replace(&mut self.route, AgentRoute::new(&self.start)).into_cells();
+                    self.solution = route[1..route.len() - 1].to_vec();
+                    self.status = if self.solution.is_empty() { SolverStatus::Done } else { SolverStatus::Solved };
+                }
+                else if !self.seen_states.insert((self.current_cell, self.heading, self.turn_sum, self.following))
+                    || self.cells_expanded > move_limit {
+                    self.status = SolverStatus::Failed;
+                }
+            }
+            SolverStatus::Solved => {
+                if let Some(cell) = self.solution.pop() {
+                    maze.change_cell_state(&cell, CellState::Solution);
+                }
+                if self.solution.is_empty() {
+                    self.status = SolverStatus::Done;
+                }
+            }
+            SolverStatus::Done | SolverStatus::Failed => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &SolverStatus {
+        &self.status
+    }
+
+    fn reset(&self, maze: &mut Maze) {
+        reset_solver(maze);
+    }
+
+    fn cells_expanded(&self) -> usize {
+        self.cells_expanded
+    }
+}
+
+impl Pledge {
+    pub fn new(start: &Coord, finish: &Coord, left_hand: bool) -> Self {
+        let main_direction = if finish.x.abs_diff(start.x) >= finish.y.abs_diff(start.y) {
+            if finish.x >= start.x { 1 } else { 3 }
+        }
+        else if finish.y > start.y {
+            2
+        }
+        else {
+            0
+        };
+
+        Pledge {
+            start: *start,
+            finish: *finish,
+            left_hand,
+            current_cell: *start,
+            main_direction,
+            heading: main_direction,
+            turn_sum: 0,
+            following: false,
+            route: AgentRoute::new(start),
+            seen_states: HashSet::new(),
+            solution: Vec::new(),
+            cells_expanded: 0,
+            status: SolverStatus::Initialized,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,118 @@
+use std::collections::HashSet;
+use rand::prelude::*;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::{CellState, Coord};
+use crate::solver_algorithms::solver_helpers::{move_walker, open_neighbors, reset_solver, AgentRoute, SolverAlgo, SolverStatus};
+
+pub struct RandomMouse {
+    start: Coord,
+    finish: Coord,
+    current_cell: Coord,
+    previous_cell: Coord,
+    route: AgentRoute,
+    visited: HashSet<Coord>,
+    reachable_cells: usize,
+    solution: Vec<Coord>,
+    cells_expanded: usize,
+    status: SolverStatus,
+    rng: StdRng,
+}
+
+impl SolverAlgo for RandomMouse {
+    fn find_solution(&mut self, maze: &mut Maze) {
+        match self.status {
+            SolverStatus::Initialized => {
+                maze.change_cell_state(&self.start, CellState::Start);
+                maze.change_cell_state(&self.finish, CellState::Finish);
+                self.reachable_cells = self.count_reachable_cells(maze);
+                self.status = if self.start == self.finish { SolverStatus::Done } else { SolverStatus::InProgress };
+            }
+            SolverStatus::InProgress => {
+                let mut neighbors = open_neighbors(maze, &self.current_cell);
+                if neighbors.len() > 1 {
+                    neighbors.retain(|neighbor| *neighbor != self.previous_cell);
+                }
+                let next_cell = match neighbors.choose(&mut self.rng) {
+                    Some(next_cell) => *next_cell,
+                    None => {
+                        self.status = SolverStatus::Failed;
+                        return;
+                    }
+                };
+
+                move_walker(maze, &self.current_cell, &next_cell);
+                self.route.step_to(&next_cell);
+                self.previous_cell = self.current_cell;
+                self.current_cell = next_cell;
+                self.visited.insert(next_cell);
+                self.cells_expanded += 1;
+
+                if self.current_cell == self.finish {
+                    let route = std::mem::replace(&mut self.route, AgentRoute::new(&self.start)).into_cells();
+                    self.solution = route[1..route.len() - 1].to_vec();
+                    self.status = if self.solution.is_empty() { SolverStatus::Done } else { SolverStatus::Solved };
+                }
+                else if self.visited.len() >= self.reachable_cells {
+                    self.status = SolverStatus::Failed;
+                }
+            }
+            SolverStatus::Solved => {
+                if let Some(cell) = self.solution.pop() {
+                    maze.change_cell_state(&cell, CellState::Solution);
+                }
+                if self.solution.is_empty() {
+                    self.status = SolverStatus::Done;
+                }
+            }
+            SolverStatus::Done | SolverStatus::Failed => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &SolverStatus {
+        &self.status
+    }
+
+    fn reset(&self, maze: &mut Maze) {
+        reset_solver(maze);
+    }
+
+    fn cells_expanded(&self) -> usize {
+        self.cells_expanded
+    }
+}
+
+impl RandomMouse {
+    pub fn new(start: &Coord, finish: &Coord, seed: u64) -> Self {
+        RandomMouse {
+            start: *start,
+            finish: *finish,
+            current_cell: *start,
+            previous_cell: *start,
+            route: AgentRoute::new(start),
+            visited: HashSet::from([*start]),
+            reachable_cells: 0,
+            solution: Vec::new(),
+            cells_expanded: 0,
+            status: SolverStatus::Initialized,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    fn count_reachable_cells(&self, maze: &Maze) -> usize {
+        let mut reachable: HashSet<Coord> = HashSet::from([self.start]);
+        let mut stack: Vec<Coord> = vec![self.start];
+
+        while let Some(cell) = stack.pop() {
+            for neighbor in open_neighbors(maze, &cell) {
+                if reachable.insert(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        reachable.len()
+    }
+}
\ No newline at end of file
@@ -1,10 +1,23 @@
-use std::collections::HashMap;
-use dioxus::prelude::Signal;
+use std::collections::{HashMap, VecDeque};
+use rand::prelude::*;
 use crate::structures::cell::{CellState, Coord};
 use crate::structures::maze::Maze;
+use crate::structures::topology::Topology;
+use crate::structures::algo_params::{param_choice, param_number, AlgoParam, AlgoParamKind, AlgoParams};
+use crate::solver_algorithms::a_star::AStar;
+use crate::solver_algorithms::bidirectional_bfs::BidirectionalBfs;
 use crate::solver_algorithms::breadth_first_search::BreadthFirstSearch;
+use crate::solver_algorithms::cul_de_sac_filling::CulDeSacFilling;
+use crate::solver_algorithms::dead_end_filling::DeadEndFilling;
 use crate::solver_algorithms::depth_first_search::DepthFirstSearch;
 use crate::solver_algorithms::djikstras::Djikstras;
+use crate::solver_algorithms::fog_of_war::FogOfWar;
+use crate::solver_algorithms::greedy_best_first::GreedyBestFirst;
+use crate::solver_algorithms::pledge::Pledge;
+use crate::solver_algorithms::random_mouse::RandomMouse;
+use crate::solver_algorithms::tremaux::Tremaux;
+use crate::solver_algorithms::wall_follower::WallFollower;
+use crate::solver_algorithms::waypoint_tour::WaypointTour;
 
 #[derive(PartialEq)]
 pub enum SolverStatus {
@@ -12,41 +25,387 @@ pub enum SolverStatus {
     InProgress,
     Solved,
     Done,
+    Failed,
 }
 
 pub trait SolverAlgo {
-    fn find_solution(&mut self, maze: &mut Signal<Maze>);
+    fn find_solution(&mut self, maze: &mut Maze);
 
     fn status(&self) -> &SolverStatus;
 
-    fn reset(&self, maze: &mut Signal<Maze>);
+    fn reset(&self, maze: &mut Maze);
+
+    fn cells_expanded(&self) -> usize;
 }
 
-pub fn get_solver_options() -> Vec<(String, String)> {
-    vec![
+pub struct AgentRoute {
+    cells: Vec<Coord>,
+    positions: HashMap<Coord, usize>,
+}
+
+#[derive(Clone, Copy)]
+pub enum Heuristic {
+    Manhattan,
+    Euclidean,
+    Chebyshev,
+    Zero,
+}
+
+pub fn get_solver_options(topology: Topology) -> Vec<(String, String)> {
+    let options = vec![
+        ("a_star".to_string(), "A*".to_string()),
+        ("bidirectional_bfs".to_string(), "Bidirectional BFS".to_string()),
         ("breadth_first_search".to_string(),"Breadth First Search".to_string()),
+        ("cul_de_sac_filling".to_string(), "Cul-de-sac Filling".to_string()),
+        ("dead_end_filling".to_string(), "Dead-end Filling".to_string()),
         ("depth_first_search".to_string(),"Depth First Search".to_string()),
         ("djikstras".to_string(),"Djikstra's".to_string()),
-    ]
+        ("fog_of_war".to_string(), "Fog of War Explorer".to_string()),
+        ("greedy_best_first".to_string(), "Greedy Best First".to_string()),
+        ("pledge".to_string(), "Pledge".to_string()),
+        ("random_mouse".to_string(), "Random Mouse".to_string()),
+        ("tremaux".to_string(), "Trémaux's".to_string()),
+        ("wall_follower".to_string(), "Wall Follower".to_string()),
+        ("waypoint_tour".to_string(), "Waypoint Tour".to_string()),
+    ];
+
+    options.into_iter()
+        .filter(|(algo, _)| solver_supports_topology(algo, topology))
+        .collect()
+}
+
+pub fn solver_supports_topology(algo: &str, topology: Topology) -> bool {
+    match algo {
+        "pledge" | "tremaux" => topology == Topology::Square,
+        _ => true,
+    }
+}
+
+pub fn get_solver_params(algo: &str, topology: Topology) -> Vec<AlgoParam> {
+    match algo {
+        // Off the square grid every heuristic falls back to the step count, so there is nothing to choose
+        "a_star" | "greedy_best_first" if topology != Topology::Square => Vec::new(),
+        "a_star" => vec![heuristic_param()],
+        "fog_of_war" => vec![vision_param()],
+        "greedy_best_first" => vec![heuristic_param()],
+        "pledge" => vec![hand_param()],
+        "wall_follower" => vec![hand_param()],
+        "waypoint_tour" => vec![order_param()],
+        _ => Vec::new(),
+    }
 }
 
-pub fn get_solver_algo(algo: &str, start: &Coord, finish: &Coord) -> Box<dyn SolverAlgo> {
+pub fn get_solver_algo(algo: &str, start: &Coord, finish: &Coord, waypoints: &[Coord], seed: u64, params: &AlgoParams) -> Box<dyn SolverAlgo> {
     match algo {
+        "a_star" => Box::new(AStar::new(start, finish, Heuristic::from_param(params))),
+        "bidirectional_bfs" => Box::new(BidirectionalBfs::new(start, finish)),
         "breadth_first_search" => Box::new(BreadthFirstSearch::new(start, finish)),
+        "cul_de_sac_filling" => Box::new(CulDeSacFilling::new(start, finish)),
+        "dead_end_filling" => Box::new(DeadEndFilling::new(start, finish)),
         "djikstras" => Box::new(Djikstras::new(start, finish)),
         "depth_first_search" => Box::new(DepthFirstSearch::new(start, finish)),
+        "fog_of_war" => Box::new(FogOfWar::new(start, finish, param_number(params, "vision", 2))),
+        "greedy_best_first" => Box::new(GreedyBestFirst::new(start, finish, Heuristic::from_param(params))),
+        "pledge" => Box::new(Pledge::new(start, finish, param_choice(params, "hand", "left") == "left")),
+        "random_mouse" => Box::new(RandomMouse::new(start, finish, seed)),
+        "tremaux" => Box::new(Tremaux::new(start, finish)),
+        "wall_follower" => Box::new(WallFollower::new(start, finish, param_choice(params, "hand", "left") == "left")),
+        "waypoint_tour" => Box::new(WaypointTour::new(start, finish, waypoints, param_choice(params, "order", "optimal") == "optimal")),
         _ => panic!("you shouldn't be here"),
     }
 }
 
+pub fn get_placement_options() -> Vec<(String, String)> {
+    vec![
+        ("manual".to_string(), "Manual".to_string()),
+        ("diameter".to_string(), "Longest Path".to_string()),
+        ("border".to_string(), "Random Border Openings".to_string()),
+    ]
+}
+
+pub fn random_border_openings(maze: &Maze, rng: &mut StdRng) -> (Coord, Coord) {
+    let start_side = rng.gen_range(0..4);
+    let finish_side = (start_side + 2) % 4;
+    (random_border_cell(maze, start_side, rng), random_border_cell(maze, finish_side, rng))
+}
+
+fn random_border_cell(maze: &Maze, side: usize, rng: &mut StdRng) -> Coord {
+    match side {
+        0 => Coord{ y: 0, x: rng.gen_range(0..maze.row_width(0)) },
+        1 => {
+            let y = rng.gen_range(0..maze.height());
+            Coord{ y, x: maze.row_width(y) - 1 }
+        }
+        2 => Coord{ y: maze.height() - 1, x: rng.gen_range(0..maze.row_width(maze.height() - 1)) },
+        _ => Coord{ y: rng.gen_range(0..maze.height()), x: 0 },
+    }
+}
+
+pub fn solver_finished(status: &SolverStatus) -> bool {
+    matches!(status, SolverStatus::Done | SolverStatus::Failed)
+}
+
 pub fn reset_solver(maze: &mut Maze) {
+    maze.clear_passage_marks();
+    maze.clear_cell_labels();
     for y in 0..maze.height() {
-        for x in 0..maze.width() {
+        for x in 0..maze.row_width(y) {
             maze.change_cell_state(&Coord{ x, y}, CellState::Path);
         }
     }
 }
 
+pub fn open_neighbors(maze: &Maze, coord: &Coord) -> Vec<Coord> {
+    maze.passages(coord).into_iter()
+        .map(|(_, neighbor)| neighbor)
+        .collect()
+}
+
+// A passage under a bridge still crosses the ground of the bridged cell.
+pub fn step_cost(maze: &Maze, from: &Coord, to: &Coord) -> usize {
+    let bridged_cost = maze.bridged_cell(from, to).map_or(0, |bridged| maze.get_cell_ref(&bridged).cost());
+    maze.get_cell_ref(to).cost() + bridged_cost
+}
+
+pub fn move_walker(maze: &mut Maze, from: &Coord, to: &Coord) {
+    if !matches!(maze.get_cell_ref(from).state(), CellState::Start | CellState::Finish) {
+        maze.change_cell_state(from, CellState::Frontier);
+    }
+    if !matches!(maze.get_cell_ref(to).state(), CellState::Start | CellState::Finish) {
+        maze.change_cell_state(to, CellState::Walker);
+    }
+}
+
+pub fn unfilled_route(maze: &Maze, start: &Coord, finish: &Coord) -> Option<Vec<Coord>> {
+    let mut explored: HashMap<Coord, Coord> = HashMap::from([(*start, *start)]);
+    let mut frontier: VecDeque<Coord> = VecDeque::from([*start]);
+
+    while let Some(cell) = frontier.pop_front() {
+        if cell == *finish {
+            break;
+        }
+        for neighbor in open_neighbors(maze, &cell) {
+            if maze.get_cell_ref(&neighbor).state() != CellState::Filled && !explored.contains_key(&neighbor) {
+                explored.insert(neighbor, cell);
+                frontier.push_back(neighbor);
+            }
+        }
+    }
+
+    let mut route: Vec<Coord> = Vec::new();
+    let mut cell = *explored.get(finish)?;
+    while cell != *start {
+        route.push(cell);
+        cell = *explored.get(&cell).unwrap();
+    }
+
+    Some(route)
+}
+
+pub fn solution_cost(maze: &Maze, start: &Coord, finish: &Coord) -> usize {
+    if start == finish {
+        return 0;
+    }
+
+    let on_route = |coord: Option<Coord>| coord.is_some_and(|coord| {
+        matches!(maze.get_cell_ref(&coord).state(), CellState::Solution | CellState::Start | CellState::Finish)
+    });
+    let tunnel_cost: usize = maze.grid().iter()
+        .filter(|cell| cell.tunnel().is_some_and(|direction| {
+            on_route(maze.neighbor(cell.coord(), direction)) && on_route(maze.neighbor(cell.coord(), direction + 2))
+        }))
+        .map(|cell| cell.cost())
+        .sum();
+
+    maze.grid().iter()
+        .filter(|cell| cell.state() == CellState::Solution)
+        .map(|cell| cell.cost())
+        .sum::<usize>() + maze.get_cell_ref(finish).cost() + tunnel_cost
+}
+
 pub fn solved(explored: &HashMap<Coord, Coord>, finish: &Coord) -> bool {
     explored.contains_key(finish)
+}
+
+pub fn hand_param() -> AlgoParam {
+    AlgoParam {
+        id: "hand".to_string(),
+        label: "Hand".to_string(),
+        kind: AlgoParamKind::Choice {
+            options: vec![
+                ("left".to_string(), "Left Hand".to_string()),
+                ("right".to_string(), "Right Hand".to_string()),
+            ],
+            default: "left".to_string(),
+        },
+    }
+}
+
+pub fn order_param() -> AlgoParam {
+    AlgoParam {
+        id: "order".to_string(),
+        label: "Waypoint order".to_string(),
+        kind: AlgoParamKind::Choice {
+            options: vec![
+                ("optimal".to_string(), "Shortest Tour".to_string()),
+                ("given".to_string(), "As Listed".to_string()),
+            ],
+            default: "optimal".to_string(),
+        },
+    }
+}
+
+pub fn vision_param() -> AlgoParam {
+    AlgoParam {
+        id: "vision".to_string(),
+        label: "Vision radius".to_string(),
+        kind: AlgoParamKind::Number { min: 0, max: 10, default: 2 },
+    }
+}
+
+pub fn heuristic_param() -> AlgoParam {
+    AlgoParam {
+        id: "heuristic".to_string(),
+        label: "Heuristic".to_string(),
+        kind: AlgoParamKind::Choice {
+            options: vec![
+                ("manhattan".to_string(), "Manhattan".to_string()),
+                ("euclidean".to_string(), "Euclidean".to_string()),
+                ("chebyshev".to_string(), "Chebyshev".to_string()),
+                ("zero".to_string(), "Zero".to_string()),
+            ],
+            default: "manhattan".to_string(),
+        },
+    }
+}
+
+impl Heuristic {
+    pub fn from_param(params: &AlgoParams) -> Self {
+        match param_choice(params, "heuristic", "manhattan") {
+            "euclidean" => Heuristic::Euclidean,
+            "chebyshev" => Heuristic::Chebyshev,
+            "zero" => Heuristic::Zero,
+            _ => Heuristic::Manhattan,
+        }
+    }
+
+    pub fn estimate(&self, maze: &Maze, from: &Coord, to: &Coord) -> usize {
+        let (dx, dy) = maze.axis_distances(from, to);
+        match self {
+            Heuristic::Zero => 0,
+            _ if maze.topology() != Topology::Square => maze.topology().steps_between(from, to),
+            Heuristic::Manhattan => dx + dy,
+            Heuristic::Euclidean => ((dx * dx + dy * dy) as f64).sqrt().floor() as usize,
+            Heuristic::Chebyshev => dx.max(dy),
+        }
+    }
+}
+
+impl AgentRoute {
+    pub fn new(start: &Coord) -> Self {
+        AgentRoute {
+            cells: vec![*start],
+            positions: HashMap::from([(*start, 0)]),
+        }
+    }
+
+    pub fn step_to(&mut self, cell: &Coord) {
+        match self.positions.get(cell) {
+            Some(&position) => {
+                for removed in self.cells.drain(position + 1..) {
+                    self.positions.remove(&removed);
+                }
+            }
+            None => {
+                self.positions.insert(*cell, self.cells.len());
+                self.cells.push(*cell);
+            }
+        }
+    }
+
+    pub fn into_cells(self) -> Vec<Coord> {
+        self.cells
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::generator_algorithms::braid::tests::braided;
+    use crate::generator_algorithms::generator_helpers::tests::generate;
+
+    pub(crate) fn solve(maze: &mut Maze, algo: &str, start: &Coord, finish: &Coord) -> Box<dyn SolverAlgo> {
+        let mut solver = get_solver_algo(algo, start, finish, &[], 0, &AlgoParams::new());
+        while !solver_finished(solver.status()) {
+            solver.find_solution(maze);
+        }
+        solver
+    }
+
+    // The marked cells must form a single corridor: start and finish each have one marked neighbor,
+    // every solution cell has two, and walking them from the start arrives at the finish.
+    pub(crate) fn is_single_route(maze: &Maze, start: &Coord, finish: &Coord) -> bool {
+        let on_route = |coord: &Coord| coord == start || coord == finish || maze.get_cell_ref(coord).state() == CellState::Solution;
+        let route_neighbors = |coord: &Coord| open_neighbors(maze, coord).into_iter().filter(|neighbor| on_route(neighbor)).collect::<Vec<Coord>>();
+
+        let route: Vec<Coord> = maze.grid().iter().map(|cell| *cell.coord()).filter(|coord| on_route(coord)).collect();
+        let degrees_match = route.iter().all(|coord| {
+            let expected = if coord == start || coord == finish { 1 } else { 2 };
+            route_neighbors(coord).len() == expected
+        });
+
+        let mut reached: HashSet<Coord> = HashSet::from([*start]);
+        let mut stack: Vec<Coord> = vec![*start];
+        while let Some(cell) = stack.pop() {
+            for next in route_neighbors(&cell) {
+                if reached.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+
+        degrees_match && reached.contains(finish) && reached.len() == route.len()
+    }
+
+    pub(crate) fn route_length(maze: &Maze) -> usize {
+        maze.grid().iter().filter(|cell| cell.state() == CellState::Solution).count()
+    }
+
+    #[test]
+    fn solvers_connect_start_to_finish() {
+        let (start, finish) = (Coord{ y: 0, x: 0 }, Coord{ y: 8, x: 11 });
+        for algo in ["bidirectional_bfs", "breadth_first_search", "depth_first_search", "djikstras", "fog_of_war", "greedy_best_first", "random_mouse", "tremaux"] {
+            for seed in 0..5 {
+                let mut maze = Maze::new(9, 12);
+                generate(&mut maze, "recursive_backtracker", seed);
+                let solver = solve(&mut maze, algo, &start, &finish);
+                assert!(solver.status() == &SolverStatus::Done, "{algo} did not finish");
+                assert!(is_single_route(&maze, &start, &finish), "{algo} solution does not join start to finish");
+            }
+        }
+    }
+
+    #[test]
+    fn bidirectional_bfs_finds_the_shortest_route_through_loops() {
+        let (start, finish) = (Coord{ y: 0, x: 0 }, Coord{ y: 8, x: 11 });
+        for seed in 0..10 {
+            let mut bidirectional_maze = braided(seed, 100);
+            let mut bfs_maze = braided(seed, 100);
+            solve(&mut bidirectional_maze, "bidirectional_bfs", &start, &finish);
+            solve(&mut bfs_maze, "breadth_first_search", &start, &finish);
+            assert_eq!(route_length(&bidirectional_maze), route_length(&bfs_maze));
+        }
+    }
+
+    #[test]
+    fn searches_fail_when_the_finish_is_walled_off() {
+        let (start, finish) = (Coord{ y: 0, x: 0 }, Coord{ y: 3, x: 4 });
+        for algo in ["breadth_first_search", "depth_first_search"] {
+            let mut maze = Maze::new(4, 5);
+            let solver = solve(&mut maze, algo, &start, &finish);
+            assert!(solver.status() == &SolverStatus::Failed, "{algo} did not fail");
+        }
+    }
 }
\ No newline at end of file
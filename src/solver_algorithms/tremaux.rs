@@ -0,0 +1,162 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::{CellState, Coord};
+use crate::solver_algorithms::solver_helpers::{move_walker, reset_solver, SolverAlgo, SolverStatus};
+
+pub struct Tremaux {
+    start: Coord,
+    finish: Coord,
+    current_cell: Coord,
+    arrived_from: Option<usize>,
+    visited: HashSet<Coord>,
+    passage_marks: HashMap<(Coord, usize), u8>,
+    solution: Vec<Coord>,
+    cells_expanded: usize,
+    status: SolverStatus,
+}
+
+impl SolverAlgo for Tremaux {
+    fn find_solution(&mut self, maze: &mut Maze) {
+        match self.status {
+            SolverStatus::Initialized => {
+                maze.change_cell_state(&self.start, CellState::Start);
+                maze.change_cell_state(&self.finish, CellState::Finish);
+                self.status = if self.start == self.finish { SolverStatus::Done } else { SolverStatus::InProgress };
+            }
+            SolverStatus::InProgress => {
+                let direction = match self.choose_passage(maze) {
+                    Some(direction) => direction,
+                    None => {
+                        self.status = SolverStatus::Failed;
+                        return;
+                    }
+                };
+                let current_cell = self.current_cell;
+                self.visited.insert(current_cell);
+
+                let marks = self.mark_passage(maze, &current_cell, direction);
+                let next_cell = maze.passage(&current_cell, direction).unwrap();
+                move_walker(maze, &current_cell, &next_cell);
+                self.current_cell = next_cell;
+                self.arrived_from = Some((direction + 2) % 4);
+                self.cells_expanded += 1;
+
+                if self.current_cell == self.finish {
+                    self.status = match self.single_marked_route(maze) {
+                        Some(route) if route.is_empty() => SolverStatus::Done,
+                        Some(route) => {
+                            self.solution = route;
+                            SolverStatus::Solved
+                        }
+                        None => SolverStatus::Failed,
+                    };
+                }
+                else if marks > 2 {
+                    self.status = SolverStatus::Failed;
+                }
+            }
+            SolverStatus::Solved => {
+                if let Some(cell) = self.solution.pop() {
+                    maze.change_cell_state(&cell, CellState::Solution);
+                }
+                if self.solution.is_empty() {
+                    self.status = SolverStatus::Done;
+                }
+            }
+            SolverStatus::Done | SolverStatus::Failed => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &SolverStatus {
+        &self.status
+    }
+
+    fn reset(&self, maze: &mut Maze) {
+        reset_solver(maze);
+    }
+
+    fn cells_expanded(&self) -> usize {
+        self.cells_expanded
+    }
+}
+
+impl Tremaux {
+    pub fn new(start: &Coord, finish: &Coord) -> Self {
+        Tremaux {
+            start: *start,
+            finish: *finish,
+            current_cell: *start,
+            arrived_from: None,
+            visited: HashSet::new(),
+            passage_marks: HashMap::new(),
+            solution: Vec::new(),
+            cells_expanded: 0,
+            status: SolverStatus::Initialized,
+        }
+    }
+
+    fn passage_key(maze: &Maze, cell: &Coord, direction: usize) -> (Coord, usize) {
+        match direction {
+            0 | 3 => (maze.passage(cell, direction).unwrap(), (direction + 2) % 4),
+            _ => (*cell, direction),
+        }
+    }
+
+    fn marks(&self, maze: &Maze, cell: &Coord, direction: usize) -> u8 {
+        *self.passage_marks.get(&Self::passage_key(maze, cell, direction)).unwrap_or(&0)
+    }
+
+    fn mark_passage(&mut self, maze: &mut Maze, cell: &Coord, direction: usize) -> u8 {
+        let key = Self::passage_key(maze, cell, direction);
+        let marks = self.passage_marks.entry(key).or_insert(0);
+        *marks += 1;
+        maze.set_passage_mark(&key.0, key.1, *marks);
+        *marks
+    }
+
+    fn choose_passage(&self, maze: &Maze) -> Option<usize> {
+        let walls = maze.get_cell_ref(&self.current_cell).walls();
+        let open_passages: Vec<usize> = (0..4).filter(|direction| !walls[*direction]).collect();
+
+        if let Some(arrived_from) = self.arrived_from {
+            if self.visited.contains(&self.current_cell) && self.marks(maze, &self.current_cell, arrived_from) == 1 {
+                return Some(arrived_from);
+            }
+        }
+
+        let unmarked = open_passages.iter()
+            .copied()
+            .find(|direction| Some(*direction) != self.arrived_from && self.marks(maze, &self.current_cell, *direction) == 0);
+        if unmarked.is_some() {
+            return unmarked;
+        }
+
+        open_passages.into_iter()
+            .filter(|direction| self.marks(maze, &self.current_cell, *direction) < 2)
+            .min_by_key(|direction| self.marks(maze, &self.current_cell, *direction))
+    }
+
+    fn single_marked_route(&self, maze: &Maze) -> Option<Vec<Coord>> {
+        let mut route: Vec<Coord> = Vec::new();
+        let mut previous_cell = self.start;
+        let mut cell = self.start;
+
+        while cell != self.finish {
+            let walls = maze.get_cell_ref(&cell).walls();
+            let next_cell = (0..4)
+                .filter(|direction| !walls[*direction] && self.marks(maze, &cell, *direction) == 1)
+                .map(|direction| maze.passage(&cell, direction).unwrap())
+                .find(|next_cell| *next_cell != previous_cell);
+            previous_cell = cell;
+            cell = next_cell?;
+            if cell != self.finish {
+                route.push(cell);
+            }
+        }
+
+        Some(route)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::{CellState, Coord};
+use crate::solver_algorithms::solver_helpers::{move_walker, reset_solver, AgentRoute, SolverAlgo, SolverStatus};
+
+pub struct WallFollower {
+    start: Coord,
+    finish: Coord,
+    left_hand: bool,
+    current_cell: Coord,
+    arrived_from: usize,
+    route: AgentRoute,
+    seen_states: HashSet<(Coord, usize)>,
+    solution: Vec<Coord>,
+    cells_expanded: usize,
+    status: SolverStatus,
+}
+
+impl SolverAlgo for WallFollower {
+    fn find_solution(&mut self, maze: &mut Maze) {
+        match self.status {
+            SolverStatus::Initialized => {
+                maze.change_cell_state(&self.start, CellState::Start);
+                maze.change_cell_state(&self.finish, CellState::Finish);
+                self.status = if self.start == self.finish { SolverStatus::Done } else { SolverStatus::InProgress };
+            }
+            SolverStatus::InProgress => {
+                let next_cell = match self.next_step(maze) {
+                    Some(next_cell) => next_cell,
+                    None => {
+                        self.status = SolverStatus::Failed;
+                        return;
+                    }
+                };
+
+                move_walker(maze, &self.current_cell, &next_cell);
+                let previous_cell = maze.bridged_cell(&self.current_cell, &next_cell).unwrap_or(self.current_cell);
+                self.arrived_from = maze.direction_to(&next_cell, &previous_cell).unwrap();
+                self.route.step_to(&next_cell);
+                self.current_cell = next_cell;
+                self.cells_expanded += 1;
+
+                if self.current_cell == self.finish {
+                    let route = std::mem::replace(&mut self.route, AgentRoute::new(&self.start)).into_cells();
+                    self.solution = route[1..route.len() - 1].to_vec();
+                    self.status = if self.solution.is_empty() { SolverStatus::Done } else { SolverStatus::Solved };
+                }
+                else if !self.seen_states.insert((self.current_cell, self.arrived_from)) {
+                    self.status = SolverStatus::Failed;
+                }
+            }
+            SolverStatus::Solved => {
+                if let Some(cell) = self.solution.pop() {
+                    maze.change_cell_state(&cell, CellState::Solution);
+                }
+                if self.solution.is_empty() {
+                    self.status = SolverStatus::Done;
+                }
+            }
+            SolverStatus::Done | SolverStatus::Failed => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &SolverStatus {
+        &self.status
+    }
+
+    fn reset(&self, maze: &mut Maze) {
+        reset_solver(maze);
+    }
+
+    fn cells_expanded(&self) -> usize {
+        self.cells_expanded
+    }
+}
+
+impl WallFollower {
+    pub fn new(start: &Coord, finish: &Coord, left_hand: bool) -> Self {
+        WallFollower {
+            start: *start,
+            finish: *finish,
+            left_hand,
+            current_cell: *start,
+            arrived_from: 0,
+            route: AgentRoute::new(start),
+            seen_states: HashSet::new(),
+            solution: Vec::new(),
+            cells_expanded: 0,
+            status: SolverStatus::Initialized,
+        }
+    }
+    // Exits are tried rotating away from the passage we arrived through, towards the hand on the wall.
+    fn next_step(&self, maze: &Maze) -> Option<Coord> {
+        let direction_count = maze.direction_count(&self.current_cell);
+
+        (1..=direction_count)
+            .map(|turn| if self.left_hand {
+                (self.arrived_from + turn) % direction_count
+            }
+            else {
+                (self.arrived_from + direction_count - turn) % direction_count
+            })
+            .find_map(|direction| maze.passage(&self.current_cell, direction))
+    }
+}
\ No newline at end of file
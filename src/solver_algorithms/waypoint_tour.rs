@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+
+use crate::structures::maze::Maze;
+use crate::structures::cell::{CellState, Coord};
+use crate::solver_algorithms::flood_fill::distances_from;
+use crate::solver_algorithms::solver_helpers::{open_neighbors, reset_solver, SolverAlgo, SolverStatus};
+
+pub struct WaypointTour {
+    start: Coord,
+    finish: Coord,
+    waypoints: Vec<Coord>,
+    optimal_order: bool,
+    stops: Vec<Coord>,
+    leg: usize,
+    distances: HashMap<Coord, HashMap<Coord, usize>>,
+    solution: Vec<Coord>,
+    cells_expanded: usize,
+    status: SolverStatus,
+}
+
+impl SolverAlgo for WaypointTour {
+    fn find_solution(&mut self, maze: &mut Maze) {
+        match self.status {
+            SolverStatus::Initialized => {
+                maze.change_cell_state(&self.start, CellState::Start);
+                maze.change_cell_state(&self.finish, CellState::Finish);
+                for (index, waypoint) in self.waypoints.iter().enumerate() {
+                    if *waypoint != self.start && *waypoint != self.finish {
+                        maze.change_cell_state(waypoint, CellState::Waypoint);
+                    }
+                    maze.set_cell_label(waypoint, (index + 1).to_string());
+                }
+                self.status = SolverStatus::InProgress;
+            }
+            SolverStatus::InProgress => {
+                if self.stops.is_empty() {
+                    self.plan_stops(maze);
+                    return;
+                }
+
+                let (from, to) = (self.stops[self.leg], self.stops[self.leg + 1]);
+                let mut leg_route = self.leg_route(maze, &from, &to);
+                self.solution.append(&mut leg_route);
+                self.leg += 1;
+
+                if self.leg + 1 == self.stops.len() {
+                    self.solution.retain(|cell| *cell != self.start && *cell != self.finish && !self.waypoints.contains(cell));
+                    self.solution.reverse();
+                    self.status = if self.solution.is_empty() { SolverStatus::Done } else { SolverStatus::Solved };
+                }
+            }
+            SolverStatus::Solved => {
+                if let Some(cell) = self.solution.pop() {
+                    maze.change_cell_state(&cell, CellState::Solution);
+                }
+                if self.solution.is_empty() {
+                    self.status = SolverStatus::Done;
+                }
+            }
+            SolverStatus::Done | SolverStatus::Failed => {
+                panic!("You shouldn't be here");
+            }
+        }
+    }
+
+    fn status(&self) -> &SolverStatus {
+        &self.status
+    }
+
+    fn reset(&self, maze: &mut Maze) {
+        reset_solver(maze);
+    }
+
+    fn cells_expanded(&self) -> usize {
+        self.cells_expanded
+    }
+}
+
+impl WaypointTour {
+    pub fn new(start: &Coord, finish: &Coord, waypoints: &[Coord], optimal_order: bool) -> Self {
+        WaypointTour {
+            start: *start,
+            finish: *finish,
+            waypoints: waypoints.to_vec(),
+            optimal_order,
+            stops: Vec::new(),
+            leg: 0,
+            distances: HashMap::new(),
+            solution: Vec::new(),
+            cells_expanded: 0,
+            status: SolverStatus::Initialized,
+        }
+    }
+
+    fn plan_stops(&mut self, maze: &Maze) {
+        let mut origins: Vec<Coord> = vec![self.start];
+        origins.extend(self.waypoints.iter().copied());
+        origins.push(self.finish);
+        for origin in origins {
+            if !self.distances.contains_key(&origin) {
+                let distances = distances_from(maze, &origin);
+                self.cells_expanded += distances.len();
+                self.distances.insert(origin, distances);
+            }
+        }
+
+        let order: Vec<usize> = if self.optimal_order {
+            match self.optimal_waypoint_order() {
+                Some(order) => order,
+                None => {
+                    self.status = SolverStatus::Failed;
+                    return;
+                }
+            }
+        }
+        else {
+            (0..self.waypoints.len()).collect()
+        };
+
+        let mut stops: Vec<Coord> = vec![self.start];
+        stops.extend(order.iter().map(|index| self.waypoints[*index]));
+        stops.push(self.finish);
+        if stops.windows(2).any(|leg| self.distance(&leg[0], &leg[1]).is_none()) {
+            self.status = SolverStatus::Failed;
+            return;
+        }
+        self.stops = stops;
+    }
+
+    fn distance(&self, from: &Coord, to: &Coord) -> Option<usize> {
+        self.distances.get(from).and_then(|distances| distances.get(to)).copied()
+    }
+
+    fn optimal_waypoint_order(&self) -> Option<Vec<usize>> {
+        let waypoint_count = self.waypoints.len();
+        if waypoint_count == 0 {
+            return Some(Vec::new());
+        }
+        let full_set = (1usize << waypoint_count) - 1;
+        let mut best: Vec<Vec<Option<(usize, usize)>>> = vec![vec![None; waypoint_count]; 1 << waypoint_count];
+
+        for (index, waypoint) in self.waypoints.iter().enumerate() {
+            if let Some(distance) = self.distance(&self.start, waypoint) {
+                best[1 << index][index] = Some((distance, index));
+            }
+        }
+        for visited in 1..=full_set {
+            for last in 0..waypoint_count {
+                let Some((distance, _)) = best[visited][last] else { continue };
+                for next in 0..waypoint_count {
+                    if visited & (1 << next) != 0 {
+                        continue;
+                    }
+                    let Some(step) = self.distance(&self.waypoints[last], &self.waypoints[next]) else { continue };
+                    let candidate = &mut best[visited | (1 << next)][next];
+                    if candidate.is_none_or(|(known, _)| distance + step < known) {
+                        *candidate = Some((distance + step, last));
+                    }
+                }
+            }
+        }
+
+        let mut last = (0..waypoint_count)
+            .filter_map(|last| {
+                let (distance, _) = best[full_set][last]?;
+                let to_finish = self.distance(&self.waypoints[last], &self.finish)?;
+                Some((distance + to_finish, last))
+            })
+            .min()?
+            .1;
+
+        let mut order: Vec<usize> = Vec::with_capacity(waypoint_count);
+        let mut visited = full_set;
+        while visited != 0 {
+            order.push(last);
+            let (_, previous) = best[visited][last].unwrap();
+            visited &= !(1 << last);
+            last = previous;
+        }
+        order.reverse();
+
+        Some(order)
+    }
+
+    fn leg_route(&self, maze: &Maze, from: &Coord, to: &Coord) -> Vec<Coord> {
+        let distances = &self.distances[to];
+        let mut route: Vec<Coord> = Vec::new();
+        let mut cell = *from;
+
+        while cell != *to {
+            let distance = distances[&cell];
+            cell = open_neighbors(maze, &cell).into_iter()
+                .find(|neighbor| distances.get(neighbor) == Some(&(distance - 1)))
+                .unwrap();
+            route.push(cell);
+        }
+
+        route
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator_algorithms::braid::tests::braided;
+
+    fn permutations(items: &[Coord]) -> Vec<Vec<Coord>> {
+        if items.is_empty() {
+            return vec![Vec::new()];
+        }
+        (0..items.len())
+            .flat_map(|index| {
+                let mut rest = items.to_vec();
+                let first = rest.remove(index);
+                permutations(&rest).into_iter().map(move |mut permutation| {
+                    permutation.insert(0, first);
+                    permutation
+                })
+            })
+            .collect()
+    }
+
+    fn tour_length(maze: &Maze, stops: &[Coord]) -> usize {
+        stops.windows(2).map(|leg| distances_from(maze, &leg[0])[&leg[1]]).sum()
+    }
+
+    #[test]
+    fn optimal_order_matches_the_shortest_permutation() {
+        let (start, finish) = (Coord{ y: 0, x: 0 }, Coord{ y: 8, x: 11 });
+        let waypoint_sets = [
+            vec![Coord{ y: 7, x: 1 }, Coord{ y: 1, x: 10 }, Coord{ y: 4, x: 6 }],
+            vec![Coord{ y: 8, x: 0 }, Coord{ y: 0, x: 11 }, Coord{ y: 5, x: 3 }, Coord{ y: 2, x: 8 }],
+        ];
+        for waypoints in waypoint_sets {
+            for seed in 0..5 {
+                let mut maze = braided(seed, 50);
+                let mut tour = WaypointTour::new(&start, &finish, &waypoints, true);
+                while !matches!(tour.status, SolverStatus::Done | SolverStatus::Failed) {
+                    tour.find_solution(&mut maze);
+                }
+                assert!(tour.status == SolverStatus::Done);
+
+                let shortest = permutations(&waypoints).into_iter()
+                    .map(|order| {
+                        let mut stops = vec![start];
+                        stops.extend(order);
+                        stops.push(finish);
+                        tour_length(&maze, &stops)
+                    })
+                    .min()
+                    .unwrap();
+                assert_eq!(tour_length(&maze, &tour.stops), shortest);
+            }
+        }
+    }
+}
\ No newline at end of file
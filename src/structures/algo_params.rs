@@ -0,0 +1,36 @@
+use std::collections::HashMap;
+
+#[derive(PartialEq, Clone)]
+pub struct AlgoParam {
+    pub id: String,
+    pub label: String,
+    pub kind: AlgoParamKind,
+}
+
+#[derive(PartialEq, Clone)]
+pub enum AlgoParamKind {
+    Choice { options: Vec<(String, String)>, default: String },
+    Number { min: usize, max: usize, default: usize },
+}
+
+pub type AlgoParams = HashMap<String, String>;
+
+pub fn default_params(schema: Vec<AlgoParam>) -> AlgoParams {
+    schema.into_iter()
+        .map(|param| {
+            let default = match param.kind {
+                AlgoParamKind::Choice { default, .. } => default,
+                AlgoParamKind::Number { default, .. } => default.to_string(),
+            };
+            (param.id, default)
+        })
+        .collect()
+}
+
+pub fn param_choice<'a>(params: &'a AlgoParams, id: &str, default: &'a str) -> &'a str {
+    params.get(id).map(|value| value.as_str()).unwrap_or(default)
+}
+
+pub fn param_number(params: &AlgoParams, id: &str, default: usize) -> usize {
+    params.get(id).and_then(|value| value.parse::<usize>().ok()).unwrap_or(default)
+}
\ No newline at end of file
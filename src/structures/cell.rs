@@ -1,8 +1,12 @@
+use crate::structures::topology::MAX_DIRECTIONS;
+
 #[derive(Eq, PartialEq, Hash, Clone, Copy)]
 pub struct Cell {
     state: CellState,
-    walls: [bool; 4],
+    walls: [bool; MAX_DIRECTIONS],
     coord: Coord,
+    terrain: Terrain,
+    tunnel: Option<usize>,
 }
 
 #[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
@@ -15,22 +19,40 @@ pub struct Coord {
 pub enum CellState {
     Unvisited,
     Frontier,
+    StartFrontier,
+    FinishFrontier,
+    Walk,
+    Hunt,
+    Walker,
+    Filled,
+    Seen,
+    Waypoint,
     Path,
     Solution,
     Start,
     Finish,
 }
 
+#[derive(Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Terrain {
+    Plain,
+    Road,
+    Mud,
+    Water,
+}
+
 impl Cell {
     pub fn new(coord: Coord) -> Self {
         Self {
             state: CellState::Unvisited,
-            walls: [true, true, true, true],
+            walls: [true; MAX_DIRECTIONS],
             coord,
+            terrain: Terrain::Plain,
+            tunnel: None,
         }
     }
 
-    pub fn walls(&self) -> &[bool; 4] {
+    pub fn walls(&self) -> &[bool; MAX_DIRECTIONS] {
         &self.walls
     }
 
@@ -54,17 +76,52 @@ impl Cell {
         self.state
     }
 
-    fn remove_wall_by_int(&mut self, dir: usize) {
+    pub fn terrain(&self) -> Terrain {
+        self.terrain
+    }
+
+    pub fn set_terrain(&mut self, terrain: Terrain) {
+        self.terrain = terrain;
+    }
+
+    pub fn cost(&self) -> usize {
+        self.terrain.cost()
+    }
+
+    // A passage running under the cell, stored as the lower of the two directions it connects.
+    pub fn tunnel(&self) -> Option<usize> {
+        self.tunnel
+    }
+
+    pub fn set_tunnel(&mut self, tunnel: Option<usize>) {
+        self.tunnel = tunnel;
+    }
+
+    pub fn remove_wall(&mut self, dir: usize) {
         self.walls[dir] = false;
     }
 
-    pub fn remove_wall(&mut self, dir: &str) {
-        match dir {
-            "top" => self.remove_wall_by_int(0),
-            "right" => self.remove_wall_by_int(1),
-            "bottom" => self.remove_wall_by_int(2),
-            "left" => self.remove_wall_by_int(3),
-            _ => {},
-        }
+    pub fn add_wall(&mut self, dir: usize) {
+        self.walls[dir] = true;
     }
 }
+
+impl Terrain {
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "road" => Terrain::Road,
+            "mud" => Terrain::Mud,
+            "water" => Terrain::Water,
+            _ => Terrain::Plain,
+        }
+    }
+
+    pub fn cost(&self) -> usize {
+        match self {
+            Terrain::Road => 1,
+            Terrain::Plain => 2,
+            Terrain::Mud => 5,
+            Terrain::Water => 10,
+        }
+    }
+}
\ No newline at end of file
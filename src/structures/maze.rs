@@ -1,29 +1,138 @@
-use crate::structures::cell::{Cell, CellState, Coord};
+use crate::structures::cell::{Cell, CellState, Coord, Terrain};
 use crate::structures::maze_svg_render::MazeSvg;
+use crate::structures::topology::{Topology, Wrap};
 
 #[derive(PartialEq)]
 pub struct Maze {
     height: usize,
     width: usize,
     grid: Vec<Cell>,
+    row_offsets: Vec<usize>,
+    topology: Topology,
+    wrap: Wrap,
     svg: MazeSvg,
 }
 
 impl Maze {
     pub fn new(height: usize, width: usize) -> Self {
+        Self::with_topology(height, width, Topology::Square)
+    }
+
+    pub fn with_topology(height: usize, width: usize, topology: Topology) -> Self {
+        Self::with_wrap(height, width, topology, Wrap::None)
+    }
+
+    pub fn with_wrap(height: usize, width: usize, topology: Topology, wrap: Wrap) -> Self {
         let mut grid = Vec::with_capacity(height * width);
+        let mut row_offsets = Vec::with_capacity(height);
         for y in 0..height {
-            for x in 0..width {
+            row_offsets.push(grid.len());
+            for x in 0..topology.row_width(y, width) {
                 grid.push(Cell::new(Coord{ y, x }));
             }
         }
 
         Self {
             height,
-            width,
+            width: (0..height).map(|y| topology.row_width(y, width)).max().unwrap_or(0),
             grid,
-            svg: MazeSvg::new(height, width),
+            row_offsets,
+            topology,
+            wrap,
+            svg: MazeSvg::new(height, width, topology),
+        }
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    pub fn wrap(&self) -> Wrap {
+        self.wrap
+    }
+
+    pub fn direction_count(&self, coord: &Coord) -> usize {
+        self.topology.direction_count(coord)
+    }
+
+    pub fn neighbor(&self, coord: &Coord, direction: usize) -> Option<Coord> {
+        self.topology.neighbor(coord, direction, self.height, self.width)
+            .or_else(|| self.topology.wrapped_neighbor(coord, direction, self.height, self.width, self.wrap))
+    }
+
+    pub fn crosses_edge(&self, coord: &Coord, direction: usize) -> bool {
+        self.topology.wrapped_neighbor(coord, direction, self.height, self.width, self.wrap).is_some()
+    }
+
+    pub fn axis_distances(&self, from: &Coord, to: &Coord) -> (usize, usize) {
+        let (dx, dy) = (from.x.abs_diff(to.x), from.y.abs_diff(to.y));
+        (
+            if self.wrap.wraps_columns() { dx.min(self.width - dx) } else { dx },
+            if self.wrap.wraps_rows() { dy.min(self.height - dy) } else { dy },
+        )
+    }
+
+    pub fn neighbors(&self, coord: &Coord) -> Vec<(usize, Coord)> {
+        (0..self.direction_count(coord))
+            .filter_map(|direction| self.neighbor(coord, direction).map(|neighbor| (direction, neighbor)))
+            .collect()
+    }
+
+    pub fn tunnels_under(&self, coord: &Coord, direction: usize) -> bool {
+        self.topology == Topology::Square && self.get_cell_ref(coord).tunnel() == Some(direction % 2)
+    }
+
+    // An open wall leads into the neighbor, unless the neighbor carries a tunnel along the same axis,
+    // in which case the passage runs underneath it to the cell beyond.
+    pub fn passage(&self, coord: &Coord, direction: usize) -> Option<Coord> {
+        if self.get_cell_ref(coord).walls()[direction] {
+            return None;
+        }
+        let neighbor = self.neighbor(coord, direction)?;
+        if self.tunnels_under(&neighbor, direction) {
+            return self.neighbor(&neighbor, direction);
+        }
+        Some(neighbor)
+    }
+
+    pub fn passages(&self, coord: &Coord) -> Vec<(usize, Coord)> {
+        (0..self.direction_count(coord))
+            .filter_map(|direction| self.passage(coord, direction).map(|cell| (direction, cell)))
+            .collect()
+    }
+
+    pub fn bridged_cell(&self, from: &Coord, to: &Coord) -> Option<Coord> {
+        (0..self.direction_count(from))
+            .filter(|direction| self.passage(from, *direction) == Some(*to))
+            .find_map(|direction| self.neighbor(from, direction).filter(|neighbor| neighbor != to))
+    }
+
+    // The neighbor must be a straight corridor crossing the direction of travel, with a cell beyond it to come out in.
+    pub fn tunnel_target(&self, from: &Coord, direction: usize) -> Option<Coord> {
+        if self.topology != Topology::Square {
+            return None;
         }
+        let under = self.neighbor(from, direction)?;
+        let target = self.neighbor(&under, direction).filter(|target| target != from)?;
+        let walls = self.get_cell_ref(&under).walls();
+        let crossing = walls[direction] && walls[(direction + 2) % 4] && !walls[(direction + 1) % 4] && !walls[(direction + 3) % 4];
+        (crossing && self.get_cell_ref(&under).tunnel().is_none()).then_some(target)
+    }
+
+    pub fn tunnel_under(&mut self, from: &Coord, direction: usize) {
+        let Some(under) = self.neighbor(from, direction) else { return };
+        let Some(to) = self.neighbor(&under, direction) else { return };
+        let opposite = (direction + 2) % 4;
+        self.cell_mut(from).remove_wall(direction);
+        self.cell_mut(&to).remove_wall(opposite);
+        self.cell_mut(&under).set_tunnel(Some(direction % 2));
+        self.remove_square_wall(from, direction, &under, opposite);
+        self.remove_square_wall(&to, opposite, &under, direction);
+        self.svg.add_bridge(&under, direction);
+    }
+
+    pub fn direction_to(&self, from: &Coord, to: &Coord) -> Option<usize> {
+        (0..self.direction_count(from)).find(|direction| self.neighbor(from, *direction) == Some(*to))
     }
 
     pub fn width(&self) -> usize {
@@ -34,6 +143,27 @@ impl Maze {
         self.height
     }
 
+    pub fn row_width(&self, row: usize) -> usize {
+        self.topology.row_width(row, self.width)
+    }
+
+    pub fn cell_count(&self) -> usize {
+        self.grid.len()
+    }
+
+    pub fn contains(&self, coord: &Coord) -> bool {
+        coord.y < self.height && coord.x < self.row_width(coord.y)
+    }
+
+    pub fn clamp_coord(&self, coord: &Coord) -> Coord {
+        let y = coord.y.min(self.height - 1);
+        Coord{ y, x: coord.x.min(self.row_width(y) - 1) }
+    }
+
+    pub fn cell_index(&self, coord: &Coord) -> usize {
+        self.row_offsets[coord.y] + coord.x
+    }
+
     pub fn grid(&self) -> &Vec<Cell> {
         &self.grid
     }
@@ -43,21 +173,206 @@ impl Maze {
     }
 
     pub fn get_cell_ref(&self, coord: &Coord) -> &Cell {
-        &self.grid[coord.y * self.width + coord.x]
+        &self.grid[self.cell_index(coord)]
+    }
+
+    fn cell_mut(&mut self, coord: &Coord) -> &mut Cell {
+        let index = self.cell_index(coord);
+        &mut self.grid[index]
     }
 
     pub fn visit_cell(&mut self, coord: &Coord) {
-        self.grid[coord.y * self.width + coord.x].visit();
-        self.svg.update_cell_color(coord, CellState::Path);
+        self.cell_mut(coord).visit();
+        let color = self.cell_color(coord);
+        self.svg.update_cell_color(coord, &color);
     }
 
     pub fn change_cell_state(&mut self, coord: &Coord, new_state: CellState) {
-        self.grid[coord.y * self.width + coord.x].change_state(new_state);
-        self.svg.update_cell_color(coord, new_state);
+        self.cell_mut(coord).change_state(new_state);
+        let color = self.cell_color(coord);
+        self.svg.update_cell_color(coord, &color);
     }
 
-    pub fn remove_cell_wall(&mut self, coord: &Coord, wall_side: &str) {
-        self.grid[coord.y * self.width + coord.x].remove_wall(wall_side);
-        self.svg.remove_cell_wall(coord, wall_side);
+    pub fn set_cell_terrain(&mut self, coord: &Coord, terrain: Terrain) {
+        self.cell_mut(coord).set_terrain(terrain);
+        let color = self.cell_color(coord);
+        self.svg.update_cell_color(coord, &color);
+    }
+
+    fn cell_color(&self, coord: &Coord) -> String {
+        let cell = self.get_cell_ref(coord);
+        match cell.state() {
+            CellState::Path => self.svg.get_terrain_color(&cell.terrain()),
+            state => self.svg.get_cell_color(&state),
+        }
+    }
+
+    pub fn paint_cell(&mut self, coord: &Coord, color: &str) {
+        self.svg.update_cell_color(coord, color);
+    }
+
+    pub fn set_cell_label(&mut self, coord: &Coord, label: String) {
+        self.svg.set_cell_label(coord, label);
+    }
+
+    pub fn clear_cell_labels(&mut self) {
+        self.svg.clear_cell_labels();
+    }
+
+    pub fn remove_wall_between(&mut self, from: &Coord, to: &Coord) {
+        let (Some(direction), Some(opposite)) = (self.direction_to(from, to), self.direction_to(to, from)) else { return };
+        self.cell_mut(from).remove_wall(direction);
+        self.cell_mut(to).remove_wall(opposite);
+        match self.topology {
+            Topology::Square => self.remove_square_wall(from, direction, to, opposite),
+            _ => self.svg.remove_wall_path(self.wall_key(from, direction)),
+        }
+    }
+
+    fn remove_square_wall(&mut self, from: &Coord, direction: usize, to: &Coord, opposite: usize) {
+        if self.crosses_edge(from, direction) {
+            self.svg.remove_cell_wall(from, direction);
+            self.svg.remove_cell_wall(to, opposite);
+            self.svg.set_edge_crossing(from, direction, true);
+            self.svg.set_edge_crossing(to, opposite, true);
+        }
+        else {
+            self.svg.remove_cell_wall(from, direction);
+        }
+    }
+
+    pub fn add_wall_between(&mut self, from: &Coord, to: &Coord) {
+        let (Some(direction), Some(opposite)) = (self.direction_to(from, to), self.direction_to(to, from)) else { return };
+        self.cell_mut(from).add_wall(direction);
+        self.cell_mut(to).add_wall(opposite);
+        match self.topology {
+            Topology::Square if self.crosses_edge(from, direction) => {
+                self.svg.add_cell_wall(from, direction);
+                self.svg.add_cell_wall(to, opposite);
+                self.svg.set_edge_crossing(from, direction, false);
+                self.svg.set_edge_crossing(to, opposite, false);
+            }
+            Topology::Square => self.svg.add_cell_wall(from, direction),
+            _ => self.svg.add_wall_path(self.wall_key(from, direction), from, direction),
+        }
+    }
+
+    fn wall_key(&self, coord: &Coord, direction: usize) -> (usize, usize, usize) {
+        self.topology.wall_key(coord, direction, self.height, self.width)
+    }
+
+    // Only used by recursive division, which splits the grid into rectangles, so the directions are the square ones
+    pub fn remove_interior_walls(&mut self) {
+        debug_assert!(self.topology == Topology::Square, "Interior walls can only be removed on a square grid");
+        for cell in self.grid.iter_mut() {
+            let coord = *cell.coord();
+            if coord.y > 0 {
+                cell.remove_wall(0);
+            }
+            if coord.x < self.width - 1 {
+                cell.remove_wall(1);
+            }
+            if coord.y < self.height - 1 {
+                cell.remove_wall(2);
+            }
+            if coord.x > 0 {
+                cell.remove_wall(3);
+            }
+        }
+        self.svg.remove_interior_walls();
+    }
+
+    pub fn set_passage_mark(&mut self, coord: &Coord, direction: usize, count: u8) {
+        self.svg.set_passage_mark(coord, direction, count);
+    }
+
+    pub fn clear_passage_marks(&mut self) {
+        self.svg.clear_passage_marks();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The direction that leads from the neighbor straight back to the cell.
+    // Polar children sit under their parent's outward directions in order, after inward and counter-clockwise.
+    // Triangles flip orientation across every side, so the same index points back. Octagon and square
+    // directions are both spaced evenly clockwise from north, so they turn half way round the compass.
+    fn opposite(maze: &Maze, coord: &Coord, direction: usize, neighbor: &Coord) -> usize {
+        match maze.topology() {
+            Topology::Square => (direction + 2) % 4,
+            Topology::Hex => (direction + 3) % 6,
+            Topology::Polar if coord.y == 0 => 0,
+            Topology::Polar if direction == 0 && coord.y == 1 => coord.x,
+            Topology::Polar if direction == 0 => 2 + coord.x % (maze.direction_count(neighbor) - 3),
+            Topology::Polar if direction == 1 => maze.direction_count(neighbor) - 1,
+            Topology::Polar if direction == maze.direction_count(coord) - 1 => 1,
+            Topology::Polar => 0,
+            Topology::Delta => direction,
+            Topology::Upsilon => {
+                let compass_point = direction * 8 / maze.direction_count(coord);
+                (compass_point + 4) % 8 * maze.direction_count(neighbor) / 8
+            }
+        }
+    }
+
+    #[test]
+    fn neighbors_lead_back_through_the_opposite_direction() {
+        let topologies = [Topology::Square, Topology::Hex, Topology::Polar, Topology::Delta, Topology::Upsilon];
+        let cases = topologies.into_iter().flat_map(|topology| [Wrap::None, Wrap::Cylinder, Wrap::Torus].map(|wrap| (topology, wrap)));
+        for (topology, wrap) in cases {
+            for (height, width) in [(2, 2), (3, 3), (6, 9)] {
+                let maze = Maze::with_wrap(height, width, topology, wrap);
+                for cell in maze.grid() {
+                    let coord = *cell.coord();
+                    for (direction, neighbor) in maze.neighbors(&coord) {
+                        let back = opposite(&maze, &coord, direction, &neighbor);
+                        assert_eq!(maze.neighbor(&neighbor, back), Some(coord), "{coord:?} direction {direction}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wrapped_edges_join_opposite_sides() {
+        let cylinder = Maze::with_wrap(4, 5, Topology::Square, Wrap::Cylinder);
+        assert_eq!(cylinder.neighbor(&Coord{ y: 2, x: 0 }, 3), Some(Coord{ y: 2, x: 4 }));
+        assert_eq!(cylinder.neighbor(&Coord{ y: 0, x: 3 }, 0), None);
+        assert!(cylinder.crosses_edge(&Coord{ y: 1, x: 4 }, 1));
+
+        let torus = Maze::with_wrap(4, 5, Topology::Square, Wrap::Torus);
+        assert_eq!(torus.neighbor(&Coord{ y: 0, x: 3 }, 0), Some(Coord{ y: 3, x: 3 }));
+        assert!(torus.grid().iter().all(|cell| torus.neighbors(cell.coord()).len() == 4));
+        assert_eq!(torus.axis_distances(&Coord{ y: 0, x: 0 }, &Coord{ y: 3, x: 4 }), (1, 1));
+
+        let narrow = Maze::with_wrap(2, 5, Topology::Square, Wrap::Torus);
+        assert_eq!(narrow.neighbor(&Coord{ y: 0, x: 1 }, 0), None);
+    }
+
+    #[test]
+    fn passages_are_symmetric_across_a_tunnel() {
+        // The corridor runs north to south over the bridged cell, the tunnel goes east under it.
+        let cases = [
+            (Maze::new(3, 3), Coord{ y: 1, x: 0 }, Coord{ y: 1, x: 1 }, Coord{ y: 1, x: 2 }),
+            (Maze::with_wrap(3, 4, Topology::Square, Wrap::Torus), Coord{ y: 1, x: 3 }, Coord{ y: 1, x: 0 }, Coord{ y: 1, x: 1 }),
+        ];
+        for (mut maze, from, under, to) in cases {
+            let (above, below) = (maze.neighbor(&under, 0).unwrap(), maze.neighbor(&under, 2).unwrap());
+            maze.remove_wall_between(&above, &under);
+            maze.remove_wall_between(&under, &below);
+            maze.tunnel_under(&from, 1);
+
+            assert_eq!(maze.passage(&from, 1), Some(to));
+            assert_eq!(maze.passage(&to, 3), Some(from));
+            assert_eq!(maze.bridged_cell(&from, &to), Some(under));
+            assert_eq!(maze.passages(&under), vec![(0, above), (2, below)]);
+            for cell in maze.grid() {
+                for (_, next) in maze.passages(cell.coord()) {
+                    assert!(maze.passages(&next).iter().any(|(_, back)| back == cell.coord()), "{:?} -> {next:?} is one way", cell.coord());
+                }
+            }
+        }
     }
 }
\ No newline at end of file
@@ -1,13 +1,22 @@
 use std::collections::{HashSet, HashMap};
 use rayon::prelude::*;
 
-use crate::structures::cell::{CellState, Coord};
+use crate::structures::cell::{CellState, Coord, Terrain};
+use crate::structures::topology::Topology;
 
 #[derive(PartialEq)]
 pub struct MazeSvg {
     pub cells: HashMap<(usize, usize), SvgRect>,
     pub vert_walls: Vec<HashSet<SvgLine>>,
     pub horiz_walls: Vec<HashSet<SvgLine>>,
+    pub passage_marks: HashMap<(usize, usize, usize), SvgMark>,
+    pub cell_labels: HashMap<(usize, usize), SvgText>,
+    pub shapes: HashMap<(usize, usize), SvgShape>,
+    pub wall_paths: HashMap<(usize, usize, usize), SvgPath>,
+    pub edge_crossings: HashMap<(usize, usize, usize), SvgMark>,
+    pub bridges: HashMap<(usize, usize), SvgPath>,
+    pub view_box: String,
+    topology: Topology,
 }
 
 #[derive(PartialEq, Clone)]
@@ -20,6 +29,32 @@ pub struct SvgRect {
     pub stroke: String,
 }
 
+#[derive(PartialEq, Clone)]
+pub struct SvgShape {
+    pub path: String,
+    pub fill: String,
+}
+
+#[derive(PartialEq, Clone)]
+pub struct SvgPath {
+    pub path: String,
+}
+
+#[derive(PartialEq, Clone)]
+pub struct SvgMark {
+    pub cx: f32,
+    pub cy: f32,
+    pub r: f32,
+    pub fill: String,
+}
+
+#[derive(PartialEq, Clone)]
+pub struct SvgText {
+    pub x: f32,
+    pub y: f32,
+    pub text: String,
+}
+
 #[derive(Hash, Eq, PartialEq, Clone, Copy, Debug)]
 pub struct SvgLine {
     pub x1: i32,
@@ -35,9 +70,16 @@ enum WallDirection {
 }
 
 const CELL_SIZE: i32 = 3;
+const BRIDGE_INSET: f32 = 0.6;
 
 impl MazeSvg {
-    pub fn new(height: usize, width: usize) -> Self {
+    pub fn new(height: usize, width: usize, topology: Topology) -> Self {
+        let (min_x, min_y, view_width, view_height) = topology.view_box(height, width);
+        let view_box = format!("{min_x} {min_y} {view_width} {view_height}");
+        if topology != Topology::Square {
+            return Self::new_polygonal(height, width, topology, view_box);
+        }
+
         let mut cells: HashMap<(usize, usize), SvgRect> = HashMap::with_capacity(width * height);
         for y in 0..height {
             for x in 0..width {
@@ -79,10 +121,67 @@ impl MazeSvg {
         Self {
             cells,
             horiz_walls,
-            vert_walls
+            vert_walls,
+            passage_marks: HashMap::new(),
+            cell_labels: HashMap::new(),
+            shapes: HashMap::new(),
+            wall_paths: HashMap::new(),
+            edge_crossings: HashMap::new(),
+            bridges: HashMap::new(),
+            view_box,
+            topology,
         }
     }
 
+    fn new_polygonal(height: usize, width: usize, topology: Topology, view_box: String) -> Self {
+        let mut shapes: HashMap<(usize, usize), SvgShape> = HashMap::new();
+        let mut wall_paths: HashMap<(usize, usize, usize), SvgPath> = HashMap::new();
+        for y in 0..height {
+            for x in 0..topology.row_width(y, width) {
+                let coord = Coord{ y, x };
+                shapes.insert((x, y), SvgShape { path: topology.cell_outline(&coord), fill: "lightgrey".to_string() });
+                for direction in 0..topology.direction_count(&coord) {
+                    let key = topology.wall_key(&coord, direction, height, width);
+                    wall_paths.entry(key).or_insert_with(|| SvgPath { path: topology.wall_path(&coord, direction) });
+                }
+            }
+        }
+
+        Self {
+            cells: HashMap::new(),
+            vert_walls: Vec::new(),
+            horiz_walls: Vec::new(),
+            passage_marks: HashMap::new(),
+            cell_labels: HashMap::new(),
+            shapes,
+            wall_paths,
+            edge_crossings: HashMap::new(),
+            bridges: HashMap::new(),
+            view_box,
+            topology,
+        }
+    }
+
+    pub fn shapes(&self) -> &HashMap<(usize, usize), SvgShape> {
+        &self.shapes
+    }
+
+    pub fn wall_paths(&self) -> &HashMap<(usize, usize, usize), SvgPath> {
+        &self.wall_paths
+    }
+
+    pub fn view_box(&self) -> &str {
+        &self.view_box
+    }
+
+    pub fn remove_wall_path(&mut self, key: (usize, usize, usize)) {
+        self.wall_paths.remove(&key);
+    }
+
+    pub fn add_wall_path(&mut self, key: (usize, usize, usize), coord: &Coord, direction: usize) {
+        self.wall_paths.insert(key, SvgPath { path: self.topology.wall_path(coord, direction) });
+    }
+
     pub fn cells(&self) -> &HashMap<(usize, usize), SvgRect> {
         &self.cells
     }
@@ -95,60 +194,194 @@ impl MazeSvg {
         &self.horiz_walls
     }
 
-    pub fn update_cell_color(&mut self, coord: &Coord, cell_state: CellState) {
-        let color: String = self.get_cell_color(&cell_state);
+    pub fn passage_marks(&self) -> &HashMap<(usize, usize, usize), SvgMark> {
+        &self.passage_marks
+    }
+
+    // Passages are keyed from the cell on their top or left side, so callers pass direction 1 or 2.
+    pub fn set_passage_mark(&mut self, coord: &Coord, direction: usize, count: u8) {
+        let (x, y) = (coord.x, coord.y);
+
+        if count == 0 {
+            self.passage_marks.remove(&(x, y, direction));
+            return;
+        }
+
+        let cell_size = CELL_SIZE as f32;
+        let (cx, cy) = match direction {
+            1 => ((x as f32 + 1.0) * cell_size, (y as f32 + 0.5) * cell_size),
+            _ => ((x as f32 + 0.5) * cell_size, (y as f32 + 1.0) * cell_size),
+        };
+        let fill = match count {
+            1 => "orange".to_string(),
+            _ => "crimson".to_string(),
+        };
+        self.passage_marks.insert((x, y, direction), SvgMark { cx, cy, r: 0.4, fill });
+    }
+
+    pub fn clear_passage_marks(&mut self) {
+        self.passage_marks.clear();
+    }
+
+    pub fn cell_labels(&self) -> &HashMap<(usize, usize), SvgText> {
+        &self.cell_labels
+    }
+
+    pub fn set_cell_label(&mut self, coord: &Coord, text: String) {
+        let (x, y) = self.topology.cell_center(coord);
+        self.cell_labels.insert((coord.x, coord.y), SvgText { x, y, text });
+    }
+
+    pub fn clear_cell_labels(&mut self) {
+        self.cell_labels.clear();
+    }
+
+    pub fn update_cell_color(&mut self, coord: &Coord, color: &str) {
         self.cells.entry((coord.x, coord.y)).and_modify(|cell| {
-            cell.fill = color.clone();
-            cell.stroke = color;
+            cell.fill = color.to_string();
+            cell.stroke = color.to_string();
+        });
+        self.shapes.entry((coord.x, coord.y)).and_modify(|shape| {
+            shape.fill = color.to_string();
         });
     }
 
-    fn get_cell_color(&self, cell_state: &CellState) -> String {
+    pub fn get_cell_color(&self, cell_state: &CellState) -> String {
         match cell_state {
             CellState::Unvisited => "lightgrey".to_string(),
             CellState::Path => "white".to_string(),
             CellState::Frontier => "papayawhip".to_string(),
+            CellState::StartFrontier => "palegreen".to_string(),
+            CellState::FinishFrontier => "mistyrose".to_string(),
+            CellState::Walk => "lightskyblue".to_string(),
+            CellState::Hunt => "khaki".to_string(),
+            CellState::Walker => "darkorange".to_string(),
+            CellState::Filled => "slategray".to_string(),
+            CellState::Seen => "lavender".to_string(),
+            CellState::Waypoint => "gold".to_string(),
             CellState::Solution => "dodgerblue".to_string(),
             CellState::Start => "green".to_string(),
             CellState::Finish => "red".to_string(),
         }
     }
 
-    pub fn remove_cell_wall(&mut self, coord: &Coord, wall_side: &str) {
-        match wall_side {
-            "top" => {
-                let wall_to_remove = SvgLine {
-                    x1: coord.x as i32 * CELL_SIZE,
-                    y1: coord.y as i32 * CELL_SIZE,
-                    x2: coord.x as i32 * CELL_SIZE + CELL_SIZE,
-                    y2: coord.y as i32 * CELL_SIZE
-                };
-                let (containing_wall, new_walls) = self.split_wall(&wall_to_remove, WallDirection::Horizontal, coord);
-                self.horiz_walls[coord.y].remove(&containing_wall);
-                self.horiz_walls[coord.y].extend(&new_walls);
-            },
-            "right" => {
-                let wall_to_remove = SvgLine {
-                    x1: coord.x as i32 * CELL_SIZE + CELL_SIZE,
-                    y1: coord.y as i32 * CELL_SIZE,
-                    x2: coord.x as i32 * CELL_SIZE + CELL_SIZE,
-                    y2: coord.y as i32 * CELL_SIZE + CELL_SIZE,
-                };
-                let (containing_wall, new_walls) = self.split_wall(&wall_to_remove, WallDirection::Vertical, coord);
-                self.vert_walls[coord.x + 1].remove(&containing_wall);
-                self.vert_walls[coord.x + 1].extend(&new_walls);
-            }
-            _ => {},
+    pub fn get_terrain_color(&self, terrain: &Terrain) -> String {
+        match terrain {
+            Terrain::Plain => "white".to_string(),
+            Terrain::Road => "wheat".to_string(),
+            Terrain::Mud => "rosybrown".to_string(),
+            Terrain::Water => "powderblue".to_string(),
+        }
+    }
+
+    pub fn remove_cell_wall(&mut self, coord: &Coord, direction: usize) {
+        let (wall_to_remove, wall_direction, index) = Self::cell_side(coord, direction);
+        let (containing_wall, new_walls) = self.split_wall(&wall_to_remove, &wall_direction, index);
+        let walls = match wall_direction {
+            WallDirection::Vertical => &mut self.vert_walls[index],
+            WallDirection::Horizontal => &mut self.horiz_walls[index],
+        };
+        walls.remove(&containing_wall);
+        walls.extend(&new_walls);
+    }
+
+    pub fn add_cell_wall(&mut self, coord: &Coord, direction: usize) {
+        let (wall_to_add, wall_direction, index) = Self::cell_side(coord, direction);
+        match wall_direction {
+            WallDirection::Vertical => Self::join_wall(&mut self.vert_walls[index], wall_to_add),
+            WallDirection::Horizontal => Self::join_wall(&mut self.horiz_walls[index], wall_to_add),
+        }
+    }
+
+    pub fn edge_crossings(&self) -> &HashMap<(usize, usize, usize), SvgMark> {
+        &self.edge_crossings
+    }
+
+    // Marks a passage leaving the grid so the matching opening on the far edge can be spotted.
+    pub fn set_edge_crossing(&mut self, coord: &Coord, direction: usize, open: bool) {
+        if !open {
+            self.edge_crossings.remove(&(coord.x, coord.y, direction));
+            return;
+        }
+
+        let cell_size = CELL_SIZE as f32;
+        let (cx, cy) = ((coord.x as f32 + 0.5) * cell_size, (coord.y as f32 + 0.5) * cell_size);
+        let (cx, cy) = match direction {
+            0 => (cx, cy - 1.0),
+            1 => (cx + 1.0, cy),
+            2 => (cx, cy + 1.0),
+            _ => (cx - 1.0, cy),
+        };
+        self.edge_crossings.insert((coord.x, coord.y, direction), SvgMark { cx, cy, r: 0.4, fill: "teal".to_string() });
+    }
+
+    pub fn bridges(&self) -> &HashMap<(usize, usize), SvgPath> {
+        &self.bridges
+    }
+
+    // The corridor over a tunnel is drawn as two railings inset from the cell edges,
+    // leaving gaps where the tunnel passes underneath.
+    pub fn add_bridge(&mut self, coord: &Coord, tunnel_direction: usize) {
+        let cell_size = CELL_SIZE as f32;
+        let (x, y) = (coord.x as f32 * cell_size, coord.y as f32 * cell_size);
+        let (near, far) = (BRIDGE_INSET, cell_size - BRIDGE_INSET);
+        let path = match tunnel_direction % 2 {
+            0 => format!("M {} {} L {} {} M {} {} L {} {}", x, y + near, x + cell_size, y + near, x, y + far, x + cell_size, y + far),
+            _ => format!("M {} {} L {} {} M {} {} L {} {}", x + near, y, x + near, y + cell_size, x + far, y, x + far, y + cell_size),
+        };
+        self.bridges.insert((coord.x, coord.y), SvgPath { path });
+    }
+
+    fn cell_side(coord: &Coord, direction: usize) -> (SvgLine, WallDirection, usize) {
+        let (x, y) = (coord.x as i32 * CELL_SIZE, coord.y as i32 * CELL_SIZE);
+        match direction {
+            0 => (SvgLine { x1: x, y1: y, x2: x + CELL_SIZE, y2: y }, WallDirection::Horizontal, coord.y),
+            1 => (SvgLine { x1: x + CELL_SIZE, y1: y, x2: x + CELL_SIZE, y2: y + CELL_SIZE }, WallDirection::Vertical, coord.x + 1),
+            2 => (SvgLine { x1: x, y1: y + CELL_SIZE, x2: x + CELL_SIZE, y2: y + CELL_SIZE }, WallDirection::Horizontal, coord.y + 1),
+            _ => (SvgLine { x1: x, y1: y, x2: x, y2: y + CELL_SIZE }, WallDirection::Vertical, coord.x),
+        }
+    }
+
+    pub fn remove_interior_walls(&mut self) {
+        let last_horiz = self.horiz_walls.len() - 1;
+        for horiz_wall_set in &mut self.horiz_walls[1..last_horiz] {
+            horiz_wall_set.clear();
+        }
+        let last_vert = self.vert_walls.len() - 1;
+        for vert_wall_set in &mut self.vert_walls[1..last_vert] {
+            vert_wall_set.clear();
+        }
+    }
+
+    fn join_wall(walls: &mut HashSet<SvgLine>, wall_to_add: SvgLine) {
+        let already_walled = walls.iter().any(|wall| {
+            wall.x1 <= wall_to_add.x1 && wall.x2 >= wall_to_add.x2 && wall.y1 <= wall_to_add.y1 && wall.y2 >= wall_to_add.y2
+        });
+        if already_walled {
+            return;
+        }
+
+        let mut joined_wall = wall_to_add;
+        if let Some(before) = walls.iter().find(|wall| wall.x2 == wall_to_add.x1 && wall.y2 == wall_to_add.y1).cloned() {
+            walls.remove(&before);
+            joined_wall.x1 = before.x1;
+            joined_wall.y1 = before.y1;
+        }
+        if let Some(after) = walls.iter().find(|wall| wall.x1 == wall_to_add.x2 && wall.y1 == wall_to_add.y2).cloned() {
+            walls.remove(&after);
+            joined_wall.x2 = after.x2;
+            joined_wall.y2 = after.y2;
         }
+        walls.insert(joined_wall);
     }
 
-    fn split_wall(&self, wall_to_remove: &SvgLine, wall_direction: WallDirection, cell_coord: &Coord) -> (SvgLine, Vec<SvgLine>) {
-        let (walls_vec, walls_vec_i): (&Vec<HashSet<SvgLine>>, usize) = match wall_direction {
-            WallDirection::Vertical => (&self.vert_walls, cell_coord.x + 1),
-            WallDirection::Horizontal => (&self.horiz_walls, cell_coord.y),
+    fn split_wall(&self, wall_to_remove: &SvgLine, wall_direction: &WallDirection, index: usize) -> (SvgLine, Vec<SvgLine>) {
+        let walls_vec: &Vec<HashSet<SvgLine>> = match wall_direction {
+            WallDirection::Vertical => &self.vert_walls,
+            WallDirection::Horizontal => &self.horiz_walls,
         };
 
-        let containing_wall = match walls_vec[walls_vec_i]
+        let containing_wall = match walls_vec[index]
             .par_iter()
             .find_any(|containing_wall| self.contains_wall(wall_to_remove, containing_wall))
             .cloned() {
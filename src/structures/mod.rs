@@ -1,3 +1,5 @@
 pub mod maze;
 pub mod cell;
-pub mod maze_svg_render;
\ No newline at end of file
+pub mod maze_svg_render;
+pub mod algo_params;
+pub mod topology;
\ No newline at end of file
@@ -0,0 +1,430 @@
+use std::f32::consts::{FRAC_1_SQRT_2, PI};
+
+use crate::structures::cell::Coord;
+
+pub const MAX_DIRECTIONS: usize = 8;
+
+const HEX_SIZE: f32 = 2.0;
+const SQRT_3: f32 = 1.732_050_8;
+const RING_SIZE: f32 = 3.0;
+const TRIANGLE_SIZE: f32 = 3.0;
+const TRIANGLE_HEIGHT: f32 = TRIANGLE_SIZE * SQRT_3 / 2.0;
+const OCTAGON_APOTHEM: f32 = 3.0 * FRAC_1_SQRT_2;
+const SQUARE_APOTHEM: f32 = 3.0 - OCTAGON_APOTHEM;
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum Topology {
+    Square,
+    Hex,
+    Polar,
+    Delta,
+    Upsilon,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum Wrap {
+    None,
+    Cylinder,
+    Torus,
+}
+
+pub fn get_topology_options() -> Vec<(String, String)> {
+    vec![
+        ("square".to_string(), "Square".to_string()),
+        ("hex".to_string(), "Hexagonal".to_string()),
+        ("polar".to_string(), "Polar".to_string()),
+        ("delta".to_string(), "Triangular".to_string()),
+        ("upsilon".to_string(), "Octagons and Squares".to_string()),
+    ]
+}
+
+pub fn get_wrap_options() -> Vec<(String, String)> {
+    vec![
+        ("none".to_string(), "No Wrap".to_string()),
+        ("cylinder".to_string(), "Cylinder (left/right)".to_string()),
+        ("torus".to_string(), "Torus (all edges)".to_string()),
+    ]
+}
+
+impl Wrap {
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "cylinder" => Wrap::Cylinder,
+            "torus" => Wrap::Torus,
+            _ => Wrap::None,
+        }
+    }
+
+    pub fn wraps_columns(&self) -> bool {
+        *self != Wrap::None
+    }
+
+    pub fn wraps_rows(&self) -> bool {
+        *self == Wrap::Torus
+    }
+}
+
+impl Topology {
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "hex" => Topology::Hex,
+            "polar" => Topology::Polar,
+            "delta" => Topology::Delta,
+            "upsilon" => Topology::Upsilon,
+            _ => Topology::Square,
+        }
+    }
+
+    pub fn row_width(&self, row: usize, width: usize) -> usize {
+        match self {
+            Topology::Polar => ring_width(row),
+            _ => width,
+        }
+    }
+
+    // Polar cells point inward, counter-clockwise, outward (one direction per subdivision) and clockwise,
+    // except for the center cell, which only has outward neighbors.
+    pub fn direction_count(&self, coord: &Coord) -> usize {
+        match self {
+            Topology::Square => 4,
+            Topology::Hex => 6,
+            Topology::Polar if coord.y == 0 => ring_width(1),
+            Topology::Polar => 3 + ring_subdivision(coord.y + 1),
+            Topology::Delta => 3,
+            Topology::Upsilon if is_octagon(coord) => 8,
+            Topology::Upsilon => 4,
+        }
+    }
+
+    pub fn neighbor(&self, coord: &Coord, direction: usize, height: usize, width: usize) -> Option<Coord> {
+        let (dy, dx): (isize, isize) = match self {
+            Topology::Polar => return polar_neighbor(coord, direction, height),
+            Topology::Square => match direction {
+                0 => (-1, 0),
+                1 => (0, 1),
+                2 => (1, 0),
+                3 => (0, -1),
+                _ => return None,
+            },
+            // Up triangles go right, down and left; down triangles go left, up and right.
+            Topology::Delta => match (direction, points_up(coord)) {
+                (0, true) | (2, false) => (0, 1),
+                (1, true) => (1, 0),
+                (1, false) => (-1, 0),
+                (2, true) | (0, false) => (0, -1),
+                _ => return None,
+            },
+            Topology::Upsilon if is_octagon(coord) => match direction {
+                0 => (-1, 0),
+                1 => (-1, 1),
+                2 => (0, 1),
+                3 => (1, 1),
+                4 => (1, 0),
+                5 => (1, -1),
+                6 => (0, -1),
+                7 => (-1, -1),
+                _ => return None,
+            },
+            Topology::Upsilon => match direction {
+                0 => (-1, 0),
+                1 => (0, 1),
+                2 => (1, 0),
+                3 => (0, -1),
+                _ => return None,
+            },
+            Topology::Hex => {
+                let odd_column = coord.x % 2 == 1;
+                match (direction, odd_column) {
+                    (0, _) => (-1, 0),
+                    (1, false) => (-1, 1),
+                    (1, true) => (0, 1),
+                    (2, false) => (0, 1),
+                    (2, true) => (1, 1),
+                    (3, _) => (1, 0),
+                    (4, false) => (0, -1),
+                    (4, true) => (1, -1),
+                    (5, false) => (-1, -1),
+                    (5, true) => (0, -1),
+                    _ => return None,
+                }
+            }
+        };
+
+        let y = coord.y.checked_add_signed(dy)?;
+        let x = coord.x.checked_add_signed(dx)?;
+        if y < height && x < width {
+            Some(Coord{ y, x })
+        }
+        else {
+            None
+        }
+    }
+
+    // Only square grids wrap, since their opposite edges line up without any offset. Edges of two cells
+    // don't wrap, or the same pair of cells would be neighbors in two directions.
+    pub fn wrapped_neighbor(&self, coord: &Coord, direction: usize, height: usize, width: usize, wrap: Wrap) -> Option<Coord> {
+        if *self != Topology::Square {
+            return None;
+        }
+        let wraps_columns = wrap.wraps_columns() && width > 2;
+        let wraps_rows = wrap.wraps_rows() && height > 2;
+        match direction {
+            0 if wraps_rows && coord.y == 0 => Some(Coord{ y: height - 1, x: coord.x }),
+            1 if wraps_columns && coord.x + 1 == width => Some(Coord{ y: coord.y, x: 0 }),
+            2 if wraps_rows && coord.y + 1 == height => Some(Coord{ y: 0, x: coord.x }),
+            3 if wraps_columns && coord.x == 0 => Some(Coord{ y: coord.y, x: width - 1 }),
+            _ => None,
+        }
+    }
+
+    pub fn direction_to(&self, from: &Coord, to: &Coord, height: usize, width: usize) -> Option<usize> {
+        (0..self.direction_count(from)).find(|direction| self.neighbor(from, *direction, height, width) == Some(*to))
+    }
+
+    pub fn wall_key(&self, coord: &Coord, direction: usize, height: usize, width: usize) -> (usize, usize, usize) {
+        let key = (coord.x, coord.y, direction);
+        let Some(neighbor) = self.neighbor(coord, direction, height, width) else { return key };
+        match self.direction_to(&neighbor, coord, height, width) {
+            Some(opposite) => key.min((neighbor.x, neighbor.y, opposite)),
+            None => key,
+        }
+    }
+
+    pub fn steps_between(&self, from: &Coord, to: &Coord) -> usize {
+        match self {
+            Topology::Square | Topology::Delta => from.x.abs_diff(to.x) + from.y.abs_diff(to.y),
+            Topology::Upsilon => from.x.abs_diff(to.x).max(from.y.abs_diff(to.y)),
+            Topology::Polar => from.y.abs_diff(to.y),
+            Topology::Hex => {
+                let cube = |coord: &Coord| {
+                    let q = coord.x as isize;
+                    let r = coord.y as isize - (q - (q & 1)) / 2;
+                    (q, r, -q - r)
+                };
+                let (from_q, from_r, from_s) = cube(from);
+                let (to_q, to_r, to_s) = cube(to);
+                from_q.abs_diff(to_q).max(from_r.abs_diff(to_r)).max(from_s.abs_diff(to_s))
+            }
+        }
+    }
+
+    pub fn cell_center(&self, coord: &Coord) -> (f32, f32) {
+        match self {
+            Topology::Square => (coord.x as f32 * 3.0 + 1.5, coord.y as f32 * 3.0 + 1.5),
+            Topology::Hex => {
+                let column_offset = if coord.x % 2 == 1 { 0.5 } else { 0.0 };
+                (
+                    HEX_SIZE * (1.0 + 1.5 * coord.x as f32),
+                    HEX_SIZE * SQRT_3 * (0.5 + coord.y as f32 + column_offset),
+                )
+            }
+            Topology::Polar if coord.y == 0 => (0.0, 0.0),
+            Topology::Polar => {
+                let (inner, outer, start, end) = polar_bounds(coord);
+                polar_point((inner + outer) / 2.0, (start + end) / 2.0)
+            }
+            Topology::Delta => {
+                let centroid_offset = if points_up(coord) { 2.0 / 3.0 } else { 1.0 / 3.0 };
+                (
+                    TRIANGLE_SIZE / 2.0 * (coord.x + 1) as f32,
+                    TRIANGLE_HEIGHT * (coord.y as f32 + centroid_offset),
+                )
+            }
+            Topology::Upsilon => (OCTAGON_APOTHEM + 3.0 * coord.x as f32, OCTAGON_APOTHEM + 3.0 * coord.y as f32),
+        }
+    }
+
+    pub fn cell_outline(&self, coord: &Coord) -> String {
+        match self {
+            Topology::Polar if coord.y == 0 => format!(
+                "M {RING_SIZE},0 A {RING_SIZE} {RING_SIZE} 0 1 1 -{RING_SIZE},0 A {RING_SIZE} {RING_SIZE} 0 1 1 {RING_SIZE},0 Z"
+            ),
+            Topology::Polar => {
+                let (inner, outer, start, end) = polar_bounds(coord);
+                let (x, y) = polar_point(inner, start);
+                let (outer_x, outer_y) = polar_point(outer, start);
+                let (inner_x, inner_y) = polar_point(inner, end);
+                format!(
+                    "M {x},{y} L {outer_x},{outer_y} {} L {inner_x},{inner_y} {} Z",
+                    arc_to(outer, start, end),
+                    arc_to(inner, end, start),
+                )
+            }
+            _ => {
+                let corners = self.cell_corners(coord);
+                let points = corners.iter()
+                    .map(|(x, y)| format!("{x},{y}"))
+                    .collect::<Vec<String>>()
+                    .join(" L ");
+                format!("M {points} Z")
+            }
+        }
+    }
+
+    pub fn wall_path(&self, coord: &Coord, direction: usize) -> String {
+        match self {
+            Topology::Polar if coord.y == 0 => {
+                let step = 2.0 * PI / ring_width(1) as f32;
+                polar_arc(RING_SIZE, step * direction as f32, step * (direction + 1) as f32)
+            }
+            Topology::Polar => {
+                let (inner, outer, start, end) = polar_bounds(coord);
+                let outward = ring_subdivision(coord.y + 1);
+                match direction {
+                    0 => polar_arc(inner, start, end),
+                    1 => polar_line(inner, outer, start),
+                    d if d < 2 + outward => {
+                        let step = (end - start) / outward as f32;
+                        polar_arc(outer, start + step * (d - 2) as f32, start + step * (d - 1) as f32)
+                    }
+                    _ => polar_line(inner, outer, end),
+                }
+            }
+            _ => {
+                let corners = self.cell_corners(coord);
+                let (x1, y1) = corners[direction];
+                let (x2, y2) = corners[(direction + 1) % corners.len()];
+                format!("M {x1},{y1} L {x2},{y2}")
+            }
+        }
+    }
+
+    // Corner i and corner i + 1 bound the wall in direction i.
+    fn cell_corners(&self, coord: &Coord) -> Vec<(f32, f32)> {
+        let (cx, cy) = self.cell_center(coord);
+        match self {
+            Topology::Hex => (0..6)
+                .map(|corner| {
+                    let angle = (240.0 + 60.0 * corner as f32).to_radians();
+                    (cx + HEX_SIZE * angle.cos(), cy + HEX_SIZE * angle.sin())
+                })
+                .collect(),
+            Topology::Delta => {
+                let left = TRIANGLE_SIZE / 2.0 * coord.x as f32;
+                let (top, bottom) = (TRIANGLE_HEIGHT * coord.y as f32, TRIANGLE_HEIGHT * (coord.y + 1) as f32);
+                let (middle, right) = (left + TRIANGLE_SIZE / 2.0, left + TRIANGLE_SIZE);
+                if points_up(coord) {
+                    vec![(middle, top), (right, bottom), (left, bottom)]
+                }
+                else {
+                    vec![(middle, bottom), (left, top), (right, top)]
+                }
+            }
+            Topology::Upsilon if is_octagon(coord) => {
+                let (near, far) = (SQUARE_APOTHEM, OCTAGON_APOTHEM);
+                vec![
+                    (cx - near, cy - far),
+                    (cx + near, cy - far),
+                    (cx + far, cy - near),
+                    (cx + far, cy + near),
+                    (cx + near, cy + far),
+                    (cx - near, cy + far),
+                    (cx - far, cy + near),
+                    (cx - far, cy - near),
+                ]
+            }
+            _ => {
+                let half_size = if *self == Topology::Upsilon { SQUARE_APOTHEM } else { 1.5 };
+                vec![
+                    (cx - half_size, cy - half_size),
+                    (cx + half_size, cy - half_size),
+                    (cx + half_size, cy + half_size),
+                    (cx - half_size, cy + half_size),
+                ]
+            }
+        }
+    }
+
+    pub fn view_box(&self, height: usize, width: usize) -> (f32, f32, f32, f32) {
+        match self {
+            Topology::Square => (-0.5, 0.0, width as f32 * 3.0 + 1.0, height as f32 * 3.0),
+            Topology::Hex => {
+                let view_height = if width > 1 { height as f32 + 0.5 } else { height as f32 };
+                (-0.5, -0.5, HEX_SIZE * (1.5 * width as f32 + 0.5) + 1.0, HEX_SIZE * SQRT_3 * view_height + 1.0)
+            }
+            Topology::Polar => {
+                let radius = RING_SIZE * height as f32 + 0.5;
+                (-radius, -radius, 2.0 * radius, 2.0 * radius)
+            }
+            Topology::Delta => (-0.5, -0.5, TRIANGLE_SIZE / 2.0 * (width + 1) as f32 + 1.0, TRIANGLE_HEIGHT * height as f32 + 1.0),
+            Topology::Upsilon => {
+                let span = |cells: usize| 3.0 * (cells - 1) as f32 + 2.0 * OCTAGON_APOTHEM + 1.0;
+                (-0.5, -0.5, span(width), span(height))
+            }
+        }
+    }
+}
+
+fn points_up(coord: &Coord) -> bool {
+    (coord.x + coord.y).is_multiple_of(2)
+}
+
+fn is_octagon(coord: &Coord) -> bool {
+    (coord.x + coord.y).is_multiple_of(2)
+}
+
+// Each ring splits its cells so they stay roughly as wide as the ring is deep.
+fn ring_width(ring: usize) -> usize {
+    (1..=ring).fold(1, |previous_width, ring| previous_width * subdivision(ring, previous_width))
+}
+
+fn ring_subdivision(ring: usize) -> usize {
+    subdivision(ring, ring_width(ring - 1))
+}
+
+fn subdivision(ring: usize, previous_width: usize) -> usize {
+    ((2.0 * PI * ring as f32 / previous_width as f32).round() as usize).max(1)
+}
+
+fn polar_neighbor(coord: &Coord, direction: usize, height: usize) -> Option<Coord> {
+    let width = ring_width(coord.y);
+    let outward = ring_subdivision(coord.y + 1);
+    let (y, x) = match direction {
+        _ if coord.y == 0 && direction < outward => (1, direction),
+        _ if coord.y == 0 => return None,
+        0 => (coord.y - 1, coord.x / ring_subdivision(coord.y)),
+        1 => (coord.y, (coord.x + width - 1) % width),
+        d if d < 2 + outward => (coord.y + 1, coord.x * outward + d - 2),
+        d if d == 2 + outward => (coord.y, (coord.x + 1) % width),
+        _ => return None,
+    };
+
+    if y < height {
+        Some(Coord{ y, x })
+    }
+    else {
+        None
+    }
+}
+
+fn polar_bounds(coord: &Coord) -> (f32, f32, f32, f32) {
+    let step = 2.0 * PI / ring_width(coord.y) as f32;
+    (
+        RING_SIZE * coord.y as f32,
+        RING_SIZE * (coord.y + 1) as f32,
+        step * coord.x as f32,
+        step * (coord.x + 1) as f32,
+    )
+}
+
+fn polar_point(radius: f32, angle: f32) -> (f32, f32) {
+    (radius * angle.cos(), radius * angle.sin())
+}
+
+fn polar_line(inner: f32, outer: f32, angle: f32) -> String {
+    let (x1, y1) = polar_point(inner, angle);
+    let (x2, y2) = polar_point(outer, angle);
+    format!("M {x1},{y1} L {x2},{y2}")
+}
+
+fn polar_arc(radius: f32, start: f32, end: f32) -> String {
+    let (x, y) = polar_point(radius, start);
+    format!("M {x},{y} {}", arc_to(radius, start, end))
+}
+
+fn arc_to(radius: f32, from: f32, to: f32) -> String {
+    let (x, y) = polar_point(radius, to);
+    let large_arc = if (to - from).abs() > PI { 1 } else { 0 };
+    let sweep = if to > from { 1 } else { 0 };
+    format!("A {radius} {radius} 0 {large_arc} {sweep} {x},{y}")
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+use dioxus::prelude::*;
+
+use crate::structures::algo_params::{AlgoParam, AlgoParamKind, AlgoParams};
+use crate::ui::components::{Dropdown::Dropdown, NumSlider::NumSlider};
+
+#[component]
+pub fn AlgoParamInput(param: AlgoParam, mut params: Signal<AlgoParams>, disabled: bool) -> Element {
+    let input_id = format!("{}-param", param.id.replace('_', "-"));
+
+    match param.kind {
+        AlgoParamKind::Choice { options, default } => {
+            let value: Signal<String> = use_signal(|| default);
+            let param_id = param.id.clone();
+            use_effect(move || {
+                params.write().insert(param_id.clone(), value());
+            });
+
+            rsx! {
+                Dropdown {
+                    id: "{input_id}",
+                    options: options,
+                    helper_text: param.label,
+                    value: value,
+                    disabled: disabled,
+                }
+            }
+        }
+        AlgoParamKind::Number { min, max, default } => {
+            let value: Signal<usize> = use_signal(|| default);
+            let param_id = param.id.clone();
+            use_effect(move || {
+                params.write().insert(param_id.clone(), value().to_string());
+            });
+
+            rsx! {
+                div {
+                    class: "algo-param-config",
+                    label { for: "{input_id}", "{param.label}: {value}" },
+                    NumSlider {
+                        id: "{input_id}",
+                        value: value,
+                        disabled: disabled,
+                        max_val: max,
+                        min_val: min,
+                        step_val: 1,
+                    }
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use dioxus::prelude::*;
+
+#[component]
+pub fn Checkbox(id: String, mut value: Signal<bool>, disabled: bool) -> Element {
+    rsx! {
+        input {
+            id: "{id}",
+            type: "checkbox",
+            disabled: disabled,
+            checked: value,
+            onchange: move |evt| value.set(evt.checked()),
+        }
+    }
+}
\ No newline at end of file
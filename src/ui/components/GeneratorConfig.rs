@@ -2,22 +2,50 @@ use dioxus::prelude::*;
 use gloo_timers::future::TimeoutFuture;
 use wasm_bindgen_futures;
 
-use crate::generator_algorithms::generator_helpers::{get_generator_algo, get_generator_options, GeneratorStatus};
+use crate::generator_algorithms::braid::Braid;
+use crate::generator_algorithms::generator_helpers::{generator_supports_topology, generator_supports_wrap, get_generator_algo, get_generator_options, get_generator_params, random_seed, GeneratorStatus};
+use crate::structures::algo_params::{AlgoParam, AlgoParams};
 use crate::structures::maze::Maze;
-use crate::ui::components::{Button::Button, Dropdown::Dropdown, NumInput::NumInput, NumSlider::NumSlider};
+use crate::structures::topology::{get_topology_options, get_wrap_options, Topology, Wrap};
+use crate::ui::components::{AlgoParamInput::AlgoParamInput, Button::Button, Checkbox::Checkbox, Dropdown::Dropdown, NumInput::NumInput, NumSlider::NumSlider};
 
 #[component]
 pub fn GeneratorConfig(maze: Signal<Maze>, generated: Signal<bool>, working: Signal<bool>) -> Element {
     let width: Signal<usize> = use_signal(|| maze.read().width());
     let height: Signal<usize> = use_signal(|| maze.read().height());
 
-    let generator_algo_choice: Signal<String> = use_signal(|| "ellers".to_string());
-    let mut generator_algo = use_signal(|| get_generator_algo(generator_algo_choice.read().as_str()));
+    let mut seed: Signal<usize> = use_signal(|| random_seed() as usize);
+    let keep_seed: Signal<bool> = use_signal(|| false);
+
+    let topology_choice: Signal<String> = use_signal(|| "square".to_string());
+    let topology: Memo<Topology> = use_memo(move || Topology::from_name(topology_choice.read().as_str()));
+    let mut generator_algo_choice: Signal<String> = use_signal(|| "ellers".to_string());
+    let mut wrap_choice: Signal<String> = use_signal(|| "none".to_string());
+    let wrap_allowed: Memo<bool> = use_memo(move || topology() == Topology::Square && generator_supports_wrap(generator_algo_choice.read().as_str()));
+    let wrap: Memo<Wrap> = use_memo(move || if wrap_allowed() { Wrap::from_name(wrap_choice.read().as_str()) } else { Wrap::None });
+
+    let generator_params: Memo<Vec<AlgoParam>> = use_memo(move || get_generator_params(generator_algo_choice.read().as_str()));
+    let params: Signal<AlgoParams> = use_signal(AlgoParams::new);
+    let mut generator_algo = use_signal(|| get_generator_algo(generator_algo_choice.read().as_str(), *seed.read() as u64, &params.read()));
+
+    let braid_percent: Signal<usize> = use_signal(|| 0);
 
     let generator_speed: Signal<usize> = use_signal(|| 1);
     let mut generator_delay: Signal<u32> = use_signal(|| *generator_speed.read() as u32 * 10);
     let mut batch_size: Signal<usize> = use_signal(|| (*width.read() * *height.read()) / 10);
 
+    use_effect(move || {
+        if !generator_supports_topology(generator_algo_choice.peek().as_str(), topology()) {
+            generator_algo_choice.set("recursive_backtracker".to_string());
+        }
+    });
+
+    use_effect(move || {
+        if !wrap_allowed() {
+            wrap_choice.set("none".to_string());
+        }
+    });
+
     use_effect(move || {
         generator_delay.set(*generator_speed.read() as u32 * 10);
         if *width.read() * *height.read() > 100 {
@@ -39,16 +67,38 @@ pub fn GeneratorConfig(maze: Signal<Maze>, generated: Signal<bool>, working: Sig
                 fieldset {
                     id: "generator-algo-config",
                     legend { "Generator Config" },
+                    Dropdown {
+                        id: "topology-dropdown",
+                        options: get_topology_options(),
+                        helper_text: "Grid".to_string(),
+                        value: topology_choice,
+                        disabled: *working.read(),
+                    }
+                    Dropdown {
+                        id: "wrap-dropdown",
+                        options: get_wrap_options(),
+                        helper_text: "Edges".to_string(),
+                        value: wrap_choice,
+                        disabled: *working.read() || !wrap_allowed(),
+                    }
                     Dropdown {
                         id: "generator-dropdown",
-                        options: get_generator_options(),
+                        options: get_generator_options(topology()),
                         helper_text: "Maze Generator Algo".to_string(),
                         value: generator_algo_choice,
                         disabled: *working.read(),
                     }
+                    for param in generator_params() {
+                        AlgoParamInput {
+                            key: "{generator_algo_choice}-{param.id}",
+                            param: param,
+                            params: params,
+                            disabled: *working.read(),
+                        }
+                    }
                     div {
                         id: "height-config",
-                        label { for: "height-input", "Height" },
+                        label { for: "height-input", if topology() == Topology::Polar { "Rings" } else { "Height" } },
                         NumInput {
                             id: "height-input",
                             value: height,
@@ -63,11 +113,43 @@ pub fn GeneratorConfig(maze: Signal<Maze>, generated: Signal<bool>, working: Sig
                         NumInput {
                             id: "width-input",
                             value: width,
-                            disabled: *working.read(),
+                            disabled: *working.read() || topology() == Topology::Polar,
                             max_val: 50,
                             min_val: 2,
                         }
                     }
+                    div {
+                        id: "braid-config",
+                        label { for: "braid-slider", "Braid: {braid_percent}%" },
+                        NumSlider {
+                            id: "braid-slider",
+                            value: braid_percent,
+                            disabled: *working.read(),
+                            max_val: 100,
+                            min_val: 0,
+                            step_val: 5,
+                        }
+                    }
+                    div {
+                        id: "seed-config",
+                        label { for: "seed-input", "Seed" },
+                        NumInput {
+                            id: "seed-input",
+                            value: seed,
+                            disabled: *working.read(),
+                            max_val: u32::MAX as usize,
+                            min_val: 0,
+                        }
+                    }
+                    div {
+                        id: "keep-seed-config",
+                        label { for: "keep-seed-checkbox", "Keep seed" },
+                        Checkbox {
+                            id: "keep-seed-checkbox",
+                            value: keep_seed,
+                            disabled: *working.read(),
+                        }
+                    }
                     div {
                         id: "generator-speed-config",
                         label { for: "generator-speed-slider", "Speed"}
@@ -88,10 +170,19 @@ pub fn GeneratorConfig(maze: Signal<Maze>, generated: Signal<bool>, working: Sig
                 onclick: move |_| {
                     generated.set(false);
                     working.set(true);
-                    generator_algo.set(get_generator_algo(generator_algo_choice.read().as_str()));
+                    if !*keep_seed.read() {
+                        seed.set(random_seed() as usize);
+                    }
+                    let algo = get_generator_algo(generator_algo_choice.read().as_str(), *seed.read() as u64, &params.read());
+                    if *braid_percent.read() > 0 {
+                        generator_algo.set(Box::new(Braid::new(algo, *seed.read() as u64, *braid_percent.read())));
+                    }
+                    else {
+                        generator_algo.set(algo);
+                    }
 
                     wasm_bindgen_futures::spawn_local(async move {
-                            maze.set(Maze::new(*height.read(), *width.read()));
+                            maze.set(Maze::with_wrap(*height.read(), *width.read(), topology(), wrap()));
                             TimeoutFuture::new(200).await;
 
                             while generator_algo.read().status() != &GeneratorStatus::Done {
@@ -99,7 +190,7 @@ pub fn GeneratorConfig(maze: Signal<Maze>, generated: Signal<bool>, working: Sig
                                     if generator_algo.read().status() == &GeneratorStatus::Done {
                                         break;
                                     }
-                                generator_algo.write().create_maze(&mut maze);
+                                generator_algo.write().create_maze(&mut maze.write());
                                 }
 
                                 if *generator_delay.read() > 0 {
@@ -0,0 +1,66 @@
+use dioxus::prelude::*;
+
+use crate::solver_algorithms::flood_fill::paint_distance_heatmap;
+use crate::structures::cell::Coord;
+use crate::structures::maze::Maze;
+use crate::ui::components::{Button::Button, Checkbox::Checkbox, NumInput::NumInput};
+
+#[component]
+pub fn HeatmapConfig(maze: Signal<Maze>, generated: Signal<bool>, working: Signal<bool>) -> Element {
+    let origin_coord_x: Signal<usize> = use_signal(|| 0);
+    let origin_coord_y: Signal<usize> = use_signal(|| 0);
+    let show_distances: Signal<bool> = use_signal(|| false);
+
+    rsx! {
+        div {
+            id: "heatmap-config",
+            class: "config-div",
+            form {
+                fieldset {
+                    id: "heatmap-algo-config",
+                    legend { "Distance Heatmap" },
+                    div {
+                        id: "origin-config",
+                        label { for: "origin-coord-config", "Origin Cell" }
+                        div {
+                            id: "origin-coord-config",
+                            label { for: "origin-coord-x", "x:" },
+                            NumInput {
+                                id: "origin-coord-x",
+                                value: origin_coord_x,
+                                disabled: *working.read(),
+                                max_val: maze.read().width() - 1,
+                                min_val: 0,
+                            }
+                            label { for: "origin-coord-y", "y:" },
+                            NumInput {
+                                id: "origin-coord-y",
+                                value: origin_coord_y,
+                                disabled: *working.read(),
+                                max_val: maze.read().height() - 1,
+                                min_val: 0,
+                            }
+                        }
+                    }
+                    div {
+                        id: "show-distances-config",
+                        label { for: "show-distances-checkbox", "Show distances" },
+                        Checkbox {
+                            id: "show-distances-checkbox",
+                            value: show_distances,
+                            disabled: *working.read(),
+                        }
+                    }
+                }
+            }
+            Button {
+                button_text: "Show heatmap".to_string(),
+                disabled: !*generated.read() || *working.read(),
+                onclick: move |_| {
+                    let origin = maze.read().clamp_coord(&Coord{ x: *origin_coord_x.read(), y: *origin_coord_y.read() });
+                    paint_distance_heatmap(&mut maze.write(), &origin, show_distances());
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
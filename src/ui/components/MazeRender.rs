@@ -1,14 +1,20 @@
 use dioxus::prelude::*;
 
+use crate::structures::cell::Coord;
 use crate::structures::maze::Maze;
 
-const CELL_SIZE: i32 = 3;
-
 #[component]
-pub fn MazeRender(maze: ReadOnlySignal<Maze>) -> Element {
+pub fn MazeRender(maze: ReadOnlySignal<Maze>, on_cell_click: EventHandler<Coord>) -> Element {
     let mut cells = use_signal(|| maze.read().svg_elements().cells().clone());
     let mut vert_walls = use_signal(|| maze.read().svg_elements().vert_walls().clone());
     let mut horiz_walls = use_signal(|| maze.read().svg_elements().horiz_walls().clone());
+    let mut passage_marks = use_signal(|| maze.read().svg_elements().passage_marks().clone());
+    let mut edge_crossings = use_signal(|| maze.read().svg_elements().edge_crossings().clone());
+    let mut cell_labels = use_signal(|| maze.read().svg_elements().cell_labels().clone());
+    let mut shapes = use_signal(|| maze.read().svg_elements().shapes().clone());
+    let mut wall_paths = use_signal(|| maze.read().svg_elements().wall_paths().clone());
+    let mut bridges = use_signal(|| maze.read().svg_elements().bridges().clone());
+    let mut view_box = use_signal(|| maze.read().svg_elements().view_box().to_string());
 
     use_effect(move || {
         let maze = maze.read();
@@ -16,16 +22,24 @@ pub fn MazeRender(maze: ReadOnlySignal<Maze>) -> Element {
         cells.set(svg_elements.cells().clone());
         vert_walls.set(svg_elements.vert_walls().clone());
         horiz_walls.set(svg_elements.horiz_walls().clone());
+        passage_marks.set(svg_elements.passage_marks().clone());
+        edge_crossings.set(svg_elements.edge_crossings().clone());
+        cell_labels.set(svg_elements.cell_labels().clone());
+        shapes.set(svg_elements.shapes().clone());
+        wall_paths.set(svg_elements.wall_paths().clone());
+        bridges.set(svg_elements.bridges().clone());
+        view_box.set(svg_elements.view_box().to_string());
     });
 
     rsx! {
         svg {
-            view_box: "{-0.5} {0} {maze.read().width() as i32 * CELL_SIZE + 1} {maze.read().height() as i32 * CELL_SIZE}",
+            view_box: "{view_box}",
 
             g {
                 id: "cells",
                 {
                     cells.read().iter().map(|(id, cell)| {
+                        let (x, y) = *id;
                         rsx!{
                             rect {
                                 id: "{id.0}x{id.1}",
@@ -34,7 +48,27 @@ pub fn MazeRender(maze: ReadOnlySignal<Maze>) -> Element {
                                 width: "{&cell.width}",
                                 height: "{&cell.height}",
                                 fill: "{&cell.fill}",
-                                stroke: "{&cell.stroke}"
+                                stroke: "{&cell.stroke}",
+                                onclick: move |_| on_cell_click.call(Coord{ x, y }),
+                            }
+                        }
+                    })
+                }
+            }
+
+            g {
+                id: "shapes",
+                {
+                    shapes.read().iter().map(|(id, shape)| {
+                        let (x, y) = *id;
+                        rsx!{
+                            path {
+                                id: "{id.0}x{id.1}",
+                                class: "cell-shape",
+                                d: "{shape.path}",
+                                fill: "{shape.fill}",
+                                stroke: "{shape.fill}",
+                                onclick: move |_| on_cell_click.call(Coord{ x, y }),
                             }
                         }
                     })
@@ -59,6 +93,18 @@ pub fn MazeRender(maze: ReadOnlySignal<Maze>) -> Element {
                     })
                 }
 
+                {
+                    wall_paths.read().iter().map(|(id, wall)| {
+                        rsx! {
+                            path {
+                                id: "{id.0}x{id.1}x{id.2}",
+                                class: "wall-path",
+                                d: "{wall.path}",
+                            }
+                        }
+                    })
+                }
+
                 {
                     vert_walls.read().iter().flat_map(|vert_wall_vec| {
                         vert_wall_vec.iter().map(|wall| {
@@ -75,6 +121,71 @@ pub fn MazeRender(maze: ReadOnlySignal<Maze>) -> Element {
                     })
                 }
             }
+
+            g {
+                id: "bridges",
+                {
+                    bridges.read().iter().map(|(id, bridge)| {
+                        rsx! {
+                            path {
+                                id: "{id.0}x{id.1}",
+                                class: "wall-path",
+                                d: "{bridge.path}",
+                            }
+                        }
+                    })
+                }
+            }
+
+            g {
+                id: "edge-crossings",
+                {
+                    edge_crossings.read().iter().map(|(id, mark)| {
+                        rsx! {
+                            circle {
+                                id: "{id.0}x{id.1}x{id.2}",
+                                cx: "{mark.cx}",
+                                cy: "{mark.cy}",
+                                r: "{mark.r}",
+                                fill: "{mark.fill}",
+                            }
+                        }
+                    })
+                }
+            }
+
+            g {
+                id: "passage-marks",
+                {
+                    passage_marks.read().iter().map(|(id, mark)| {
+                        rsx! {
+                            circle {
+                                id: "{id.0}x{id.1}x{id.2}",
+                                cx: "{mark.cx}",
+                                cy: "{mark.cy}",
+                                r: "{mark.r}",
+                                fill: "{mark.fill}",
+                            }
+                        }
+                    })
+                }
+            }
+
+            g {
+                id: "cell-labels",
+                {
+                    cell_labels.read().iter().map(|(id, label)| {
+                        rsx! {
+                            text {
+                                id: "label-{id.0}x{id.1}",
+                                x: "{label.x}",
+                                y: "{label.y}",
+                                "{label.text}"
+                            }
+                        }
+                    })
+                }
+            }
         }
     }
 }
\ No newline at end of file
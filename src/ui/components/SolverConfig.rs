@@ -1,39 +1,68 @@
 use dioxus::prelude::*;
 use gloo_timers::future::TimeoutFuture;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 use wasm_bindgen_futures;
 
-use crate::solver_algorithms::solver_helpers::{get_solver_algo, get_solver_options, SolverStatus};
+use crate::generator_algorithms::generator_helpers::random_seed;
+use crate::solver_algorithms::flood_fill::maze_diameter;
+use crate::solver_algorithms::solver_helpers::{get_placement_options, get_solver_algo, get_solver_options, get_solver_params, random_border_openings, solution_cost, solver_finished, solver_supports_topology, SolverStatus};
+use crate::structures::algo_params::{AlgoParam, AlgoParams};
 use crate::structures::cell::Coord;
 use crate::structures::maze::Maze;
-use crate::ui::components::{Dropdown::Dropdown, NumInput::NumInput, Button::Button, NumSlider::NumSlider};
+use crate::structures::topology::Topology;
+use crate::ui::components::{AlgoParamInput::AlgoParamInput, Checkbox::Checkbox, Dropdown::Dropdown, NumInput::NumInput, Button::Button, NumSlider::NumSlider};
+
+const MAX_WAYPOINTS: usize = 10;
 
 #[component]
 pub fn SolverConfig(maze: Signal<Maze>, generated: Signal<bool>, working: Signal<bool>) -> Element {
     let height: Memo<usize> = use_memo(move || { maze.read().height() });
     let width: Memo<usize> = use_memo(move || { maze.read().width() });
+    let topology: Memo<Topology> = use_memo(move || { maze.read().topology() });
 
-    let start_coord_x: Signal<usize> = use_signal(|| 0);
-    let start_coord_y: Signal<usize> = use_signal(|| 0);
+    let mut start_coord_x: Signal<usize> = use_signal(|| 0);
+    let mut start_coord_y: Signal<usize> = use_signal(|| 0);
     let mut finish_coord_x: Signal<usize> = use_signal(|| maze.read().width() - 1);
     let mut finish_coord_y: Signal<usize> = use_signal(|| maze.read().height() - 1);
     let mut start_coord: Signal<Coord> = use_signal(|| { Coord{ x: start_coord_x(), y: start_coord_y() } });
     let mut finish_coord: Signal<Coord> = use_signal(|| { Coord{ x: finish_coord_x(), y: finish_coord_y() } });
+    let placement: Signal<String> = use_signal(|| "manual".to_string());
+    let manual_placement: Memo<bool> = use_memo(move || placement.read().as_str() == "manual");
+    let waypoint_coord_x: Signal<usize> = use_signal(|| 0);
+    let waypoint_coord_y: Signal<usize> = use_signal(|| 0);
+    let mut waypoints: Signal<Vec<Coord>> = use_signal(Vec::new);
     let mut solved: Signal<bool> = use_signal(|| false);
+    let mut cells_expanded: Signal<Option<usize>> = use_signal(|| None);
+    let mut route_cost: Signal<Option<usize>> = use_signal(|| None);
+    let mut solver_failed: Signal<bool> = use_signal(|| false);
+
+    let mut seed: Signal<usize> = use_signal(|| random_seed() as usize);
+    let keep_seed: Signal<bool> = use_signal(|| false);
 
-    let solver_algo_choice: Signal<String> = use_signal(|| "breadth_first_search".to_string());
-    let mut solver_algo = use_signal(|| get_solver_algo(solver_algo_choice.read().as_str(), &start_coord(), &finish_coord()));
+    let mut solver_algo_choice: Signal<String> = use_signal(|| "breadth_first_search".to_string());
+    let solver_params: Memo<Vec<AlgoParam>> = use_memo(move || get_solver_params(solver_algo_choice.read().as_str(), topology()));
+    let params: Signal<AlgoParams> = use_signal(AlgoParams::new);
+    let mut solver_algo = use_signal(|| get_solver_algo(solver_algo_choice.read().as_str(), &start_coord(), &finish_coord(), &waypoints.read(), *seed.read() as u64, &params.read()));
 
     let solver_speed: Signal<usize> = use_signal(|| 1);
     let mut solver_delay: Signal<u32> = use_signal(|| *solver_speed.read() as u32 * 10);
     let mut batch_size: Signal<usize> = use_signal(|| (maze.read().width() * maze.read().height()) / 50);
 
     use_effect(move || {
-        solver_algo.set(get_solver_algo(solver_algo_choice.read().as_str(), &start_coord(), &finish_coord()));
+        if !solver_supports_topology(solver_algo_choice.peek().as_str(), topology()) {
+            solver_algo_choice.set("breadth_first_search".to_string());
+        }
+    });
+
+    use_effect(move || {
+        solver_algo.set(get_solver_algo(solver_algo_choice.read().as_str(), &start_coord(), &finish_coord(), &waypoints.read(), *seed.read() as u64, &params.read()));
     });
 
     use_effect(move || {
         finish_coord_x.set(*height.read() - 1);
         finish_coord_y.set(*width.read() - 1);
+        waypoints.write().retain(|waypoint| maze.peek().contains(waypoint));
     });
 
     use_effect(move || {
@@ -59,11 +88,48 @@ pub fn SolverConfig(maze: Signal<Maze>, generated: Signal<bool>, working: Signal
                     legend { "Solver Config" },
                     Dropdown {
                         id: "solver-dropdown",
-                        options: get_solver_options(),
+                        options: get_solver_options(topology()),
                         helper_text: "Maze Solver Algo".to_string(),
                         value: solver_algo_choice,
                         disabled: *working.read(),
                     }
+                    for param in solver_params() {
+                        AlgoParamInput {
+                            key: "{solver_algo_choice}-{param.id}",
+                            param: param,
+                            params: params,
+                            disabled: *working.read(),
+                        }
+                    }
+                    if solver_algo_choice.read().as_str() == "random_mouse" {
+                        div {
+                            id: "solver-seed-config",
+                            label { for: "solver-seed-input", "Seed" },
+                            NumInput {
+                                id: "solver-seed-input",
+                                value: seed,
+                                disabled: *working.read(),
+                                max_val: u32::MAX as usize,
+                                min_val: 0,
+                            }
+                        }
+                        div {
+                            id: "solver-keep-seed-config",
+                            label { for: "solver-keep-seed-checkbox", "Keep seed" },
+                            Checkbox {
+                                id: "solver-keep-seed-checkbox",
+                                value: keep_seed,
+                                disabled: *working.read(),
+                            }
+                        }
+                    }
+                    Dropdown {
+                        id: "placement-dropdown",
+                        options: get_placement_options(),
+                        helper_text: "Start/Finish Placement".to_string(),
+                        value: placement,
+                        disabled: *working.read(),
+                    }
                     div {
                         id: "start-finish-config",
                         label { for: "start-coord-config", "Starting Cell" }
@@ -73,7 +139,7 @@ pub fn SolverConfig(maze: Signal<Maze>, generated: Signal<bool>, working: Signal
                             NumInput {
                                 id: "start-coord-x",
                                 value: start_coord_x,
-                                disabled: *working.read(),
+                                disabled: *working.read() || !manual_placement(),
                                 max_val: maze.read().width() - 1,
                                 min_val: 0,
                             }
@@ -81,7 +147,7 @@ pub fn SolverConfig(maze: Signal<Maze>, generated: Signal<bool>, working: Signal
                             NumInput {
                                 id: "start-coord-y",
                                 value: start_coord_y,
-                                disabled: *working.read(),
+                                disabled: *working.read() || !manual_placement(),
                                 max_val: maze.read().width() - 1,
                                 min_val: 0,
                             }
@@ -93,7 +159,7 @@ pub fn SolverConfig(maze: Signal<Maze>, generated: Signal<bool>, working: Signal
                             NumInput {
                                 id: "finish-coord-x",
                                 value: finish_coord_x,
-                                disabled: *working.read(),
+                                disabled: *working.read() || !manual_placement(),
                                 max_val: maze.read().height() - 1,
                                 min_val: 0,
                             }
@@ -101,12 +167,46 @@ pub fn SolverConfig(maze: Signal<Maze>, generated: Signal<bool>, working: Signal
                             NumInput {
                                 id: "finish-coord-y",
                                 value: finish_coord_y,
-                                disabled: *working.read(),
+                                disabled: *working.read() || !manual_placement(),
                                 max_val: maze.read().height() - 1,
                                 min_val: 0,
                             }
                         }
                     }
+                    if solver_algo_choice.read().as_str() == "waypoint_tour" {
+                        div {
+                            id: "waypoint-config",
+                            label { for: "waypoint-coord-config", "Waypoint" },
+                            div {
+                                id: "waypoint-coord-config",
+                                label { for: "waypoint-coord-x", "x:" },
+                                NumInput {
+                                    id: "waypoint-coord-x",
+                                    value: waypoint_coord_x,
+                                    disabled: *working.read(),
+                                    max_val: maze.read().width() - 1,
+                                    min_val: 0,
+                                }
+                                label { for: "waypoint-coord-y", "y:" },
+                                NumInput {
+                                    id: "waypoint-coord-y",
+                                    value: waypoint_coord_y,
+                                    disabled: *working.read(),
+                                    max_val: maze.read().height() - 1,
+                                    min_val: 0,
+                                }
+                            }
+                            p {
+                                id: "waypoint-list",
+                                if waypoints.read().is_empty() {
+                                    "No waypoints"
+                                }
+                                for (index, waypoint) in waypoints.read().iter().enumerate() {
+                                    span { key: "{index}", "{index + 1}: ({waypoint.x}, {waypoint.y}) " }
+                                }
+                            }
+                        }
+                    }
                     div {
                         id: "solver-speed-config",
                         label { for: "solver-speed-config", "Speed" },
@@ -121,40 +221,96 @@ pub fn SolverConfig(maze: Signal<Maze>, generated: Signal<bool>, working: Signal
                     }
                 }
             }
+            if solver_algo_choice.read().as_str() == "waypoint_tour" {
+                div {
+                    id: "waypoint-buttons",
+                    Button {
+                        button_text: "Add waypoint".to_string(),
+                        disabled: *working.read() || waypoints.read().len() >= MAX_WAYPOINTS,
+                        onclick: move |_| {
+                            let waypoint = maze.read().clamp_coord(&Coord{ x: *waypoint_coord_x.read(), y: *waypoint_coord_y.read() });
+                            waypoints.write().push(waypoint);
+                        }
+                    }
+                    Button {
+                        button_text: "Clear waypoints".to_string(),
+                        disabled: *working.read() || waypoints.read().is_empty(),
+                        onclick: move |_| waypoints.write().clear(),
+                    }
+                }
+            }
             Button {
                 button_text: "Solve maze".to_string(),
                 disabled: !*generated.read() || *working.read(),
                 onclick: move |_| {
                     working.set(true);
-                    start_coord.set(Coord{ x: *start_coord_x.read(), y: *start_coord_y.read() });
-                    finish_coord.set(Coord{ x: *finish_coord_x.read(), y: *finish_coord_y.read() });
+                    cells_expanded.set(None);
+                    route_cost.set(None);
+                    solver_failed.set(false);
+                    if !*keep_seed.read() {
+                        seed.set(random_seed() as usize);
+                    }
+                    let placed_coords = match placement.read().as_str() {
+                        "diameter" => Some(maze_diameter(&maze.read())),
+                        "border" => Some(random_border_openings(&maze.read(), &mut StdRng::seed_from_u64(random_seed()))),
+                        _ => None,
+                    };
+                    if let Some((start, finish)) = placed_coords {
+                        start_coord_x.set(start.x);
+                        start_coord_y.set(start.y);
+                        finish_coord_x.set(finish.x);
+                        finish_coord_y.set(finish.y);
+                    }
+                    start_coord.set(maze.read().clamp_coord(&Coord{ x: *start_coord_x.read(), y: *start_coord_y.read() }));
+                    finish_coord.set(maze.read().clamp_coord(&Coord{ x: *finish_coord_x.read(), y: *finish_coord_y.read() }));
 
                     wasm_bindgen_futures::spawn_local(async move {
-                        if solved() {
-                            solver_algo.write().reset(&mut maze);
-                            solver_algo.set(get_solver_algo(solver_algo_choice.read().as_str(), &start_coord(), &finish_coord()));
-                            solved.set(false);
-                        }
+                        solver_algo.write().reset(&mut maze.write());
+                        solver_algo.set(get_solver_algo(solver_algo_choice.read().as_str(), &start_coord(), &finish_coord(), &waypoints.read(), *seed.read() as u64, &params.read()));
+                        solved.set(false);
 
-                        while solver_algo.read().status() != &SolverStatus::Done {
+                        while !solver_finished(solver_algo.read().status()) {
                             for _ in 0..*batch_size.read() {
-                                if solver_algo.read().status() == &SolverStatus::Done {
+                                if solver_finished(solver_algo.read().status()) {
                                     break;
                                 }
-                                solver_algo.write().find_solution(&mut maze);
+                                solver_algo.write().find_solution(&mut maze.write());
                             }
 
                             if *solver_delay.read() > 0 {
                                 TimeoutFuture::new(*solver_delay.read()).await;
                             }
                         }
-                        if solver_algo.read().status() == &SolverStatus::Done {
+                        if solver_finished(solver_algo.read().status()) {
+                            solver_failed.set(solver_algo.read().status() == &SolverStatus::Failed);
+                            cells_expanded.set(Some(solver_algo.read().cells_expanded()));
+                            if !solver_failed() && solver_algo_choice.read().as_str() != "waypoint_tour" {
+                                route_cost.set(Some(solution_cost(&maze.read(), &start_coord(), &finish_coord())));
+                            }
                             solved.set(true);
                             working.set(false);
                         }
                     });
                 }
             }
+            if let Some(count) = cells_expanded() {
+                p {
+                    id: "solver-stats",
+                    "Cells expanded: {count}"
+                }
+            }
+            if let Some(cost) = route_cost() {
+                p {
+                    id: "solver-cost",
+                    "Route cost: {cost}"
+                }
+            }
+            if solver_failed() {
+                p {
+                    id: "solver-failed",
+                    "No route to the finish was found"
+                }
+            }
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,58 @@
+use dioxus::prelude::*;
+
+use crate::generator_algorithms::generator_helpers::random_seed;
+use crate::generator_algorithms::terrain::{clear_terrain, get_terrain_options, scatter_terrain};
+use crate::structures::maze::Maze;
+use crate::ui::components::{Button::Button, Dropdown::Dropdown, NumSlider::NumSlider};
+
+#[component]
+pub fn TerrainConfig(maze: Signal<Maze>, generated: Signal<bool>, working: Signal<bool>, brush: Signal<String>) -> Element {
+    let terrain_percent: Signal<usize> = use_signal(|| 30);
+    let mut brush_options = vec![("none".to_string(), "No Brush".to_string())];
+    brush_options.extend(get_terrain_options());
+
+    rsx! {
+        div {
+            id: "terrain-config",
+            class: "config-div",
+            form {
+                fieldset {
+                    id: "terrain-algo-config",
+                    legend { "Terrain" },
+                    Dropdown {
+                        id: "terrain-brush-dropdown",
+                        options: brush_options,
+                        helper_text: "Paint Brush".to_string(),
+                        value: brush,
+                        disabled: *working.read(),
+                    }
+                    div {
+                        id: "terrain-percent-config",
+                        label { for: "terrain-percent-slider", "Terrain: {terrain_percent}%" },
+                        NumSlider {
+                            id: "terrain-percent-slider",
+                            value: terrain_percent,
+                            disabled: *working.read(),
+                            max_val: 100,
+                            min_val: 0,
+                            step_val: 5,
+                        }
+                    }
+                }
+            }
+            div {
+                id: "terrain-buttons",
+                Button {
+                    button_text: "Scatter terrain".to_string(),
+                    disabled: !*generated.read() || *working.read(),
+                    onclick: move |_| scatter_terrain(&mut maze.write(), random_seed(), *terrain_percent.read()),
+                }
+                Button {
+                    button_text: "Clear terrain".to_string(),
+                    disabled: !*generated.read() || *working.read(),
+                    onclick: move |_| clear_terrain(&mut maze.write()),
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
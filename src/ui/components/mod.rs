@@ -4,4 +4,8 @@ pub mod Button;
 pub mod NumInput;
 pub mod GeneratorConfig;
 pub mod SolverConfig;
-pub mod NumSlider;
\ No newline at end of file
+pub mod NumSlider;
+pub mod Checkbox;
+pub mod AlgoParamInput;
+pub mod HeatmapConfig;
+pub mod TerrainConfig;
\ No newline at end of file
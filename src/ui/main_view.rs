@@ -1,6 +1,7 @@
 use dioxus::prelude::*;
 
-use crate::ui::components::{GeneratorConfig::GeneratorConfig, MazeRender::MazeRender, SolverConfig::SolverConfig};
+use crate::ui::components::{GeneratorConfig::GeneratorConfig, HeatmapConfig::HeatmapConfig, MazeRender::MazeRender, SolverConfig::SolverConfig, TerrainConfig::TerrainConfig};
+use crate::structures::cell::{Coord, Terrain};
 use crate::structures::maze::Maze;
 
 pub fn launch_app() {
@@ -11,9 +12,10 @@ static CSS: Asset = asset!("src/ui/assets/main.css");
 // static favicon: Asset = asset!("src/ui/assets/favicon.ico");
 
 fn App() -> Element {
-    let maze: Signal<Maze> = use_signal(|| Maze::new(15, 15));
+    let mut maze: Signal<Maze> = use_signal(|| Maze::new(15, 15));
     let generated: Signal<bool> = use_signal(|| false);
     let working: Signal<bool> = use_signal(|| false);
+    let terrain_brush: Signal<String> = use_signal(|| "none".to_string());
 
     rsx!{
         document::Stylesheet { href: CSS }
@@ -39,10 +41,28 @@ fn App() -> Element {
                 generated: generated,
                 working: working,
             }
+            HeatmapConfig {
+                maze: maze,
+                generated: generated,
+                working: working,
+            }
+            TerrainConfig {
+                maze: maze,
+                generated: generated,
+                working: working,
+                brush: terrain_brush,
+            }
         }
         div {
             id: "maze",
-            MazeRender { maze: maze }
+            MazeRender {
+                maze: maze,
+                on_cell_click: move |coord: Coord| {
+                    if generated() && !working() && terrain_brush.read().as_str() != "none" {
+                        maze.write().set_cell_terrain(&coord, Terrain::from_name(terrain_brush.read().as_str()));
+                    }
+                },
+            }
         }
     }
 }
\ No newline at end of file